@@ -15,7 +15,7 @@
 use crate::r1cs::{errors::SynthesisError, Index, LinearCombination, LookupTable, Namespace, Variable};
 use snarkvm_fields::Field;
 
-use std::marker::PhantomData;
+use std::{marker::PhantomData, sync::Arc};
 
 /// Computations are expressed in terms of rank-1 constraint systems (R1CS).
 /// The `generate_constraints` method is called to generate constraints for
@@ -25,6 +25,20 @@ pub trait ConstraintSynthesizer<F: Field>: Sync {
     fn generate_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<(), SynthesisError>;
 }
 
+impl<F: Field, C: ConstraintSynthesizer<F>> ConstraintSynthesizer<F> for &C {
+    /// Drives generation of new constraints inside `CS`, using the referenced synthesizer.
+    fn generate_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+        (**self).generate_constraints(cs)
+    }
+}
+
+impl<F: Field, C: ConstraintSynthesizer<F> + Send> ConstraintSynthesizer<F> for Arc<C> {
+    /// Drives generation of new constraints inside `CS`, using the shared synthesizer.
+    fn generate_constraints<CS: ConstraintSystem<F>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+        (**self).generate_constraints(cs)
+    }
+}
+
 /// Represents a constraint system which can have new variables
 /// allocated and constrains between them formed.
 pub trait ConstraintSystem<F: Field>: Sized {
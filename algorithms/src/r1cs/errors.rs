@@ -23,6 +23,9 @@ pub enum SynthesisError {
     /// During synthesis, we lacked knowledge of a variable assignment.
     #[error("An assignment for a variable could not be computed")]
     AssignmentMissing,
+    /// During synthesis, the assignment did not match the constraint system.
+    #[error("Assignment mismatch: {}", _0)]
+    AssignmentMismatch(String),
     /// Handles a failed conversion of objects into constraint field elements.
     #[error("Failed to convert object into constraint field elements")]
     ConstraintFieldError(#[from] snarkvm_fields::ConstraintFieldError),
@@ -59,6 +62,9 @@ pub enum SynthesisError {
     /// During CRS generation, we observed an unconstrained auxiliary variable
     #[error("Auxiliary variable was unconstrained")]
     UnconstrainedVariable,
+    /// During synthesis, a value did not fit in the target integer type.
+    #[error("Integer overflow: {}", _0)]
+    Overflow(String),
 }
 
 impl From<std::io::Error> for SynthesisError {
@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_algorithms::r1cs::{ConstraintSynthesizer, TestConstraintSystem};
+use snarkvm_circuit::{
+    environment::{
+        prelude::num_traits::One as _,
+        Assignment,
+        Circuit,
+        Eject,
+        Environment,
+        Inject,
+        Mode,
+        One,
+        RecordingConstraintSystem,
+    },
+    types::Field,
+};
+
+use criterion::{BatchSize, BenchmarkId, Criterion, Throughput};
+
+/// The exponents of the example circuit, each of which yields as many constraints.
+const EXPONENTS: [u64; 3] = [64, 1024, 16384];
+
+/// The exponent of the example circuit used to compare the serial and `parallel` synthesis.
+const LARGE_EXPONENT: u64 = 1 << 18;
+
+/// Compute 2^EXPONENT - 1, in a purposefully constraint-inefficient manner for benchmarking.
+fn create_example_circuit(exponent: u64) -> Field<Circuit> {
+    let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+    let two = one + one;
+
+    let mut candidate = Field::<Circuit>::new(Mode::Public, one);
+    let mut accumulator = Field::new(Mode::Private, two);
+    for _ in 0..exponent {
+        candidate += &accumulator;
+        accumulator *= Field::new(Mode::Private, two);
+    }
+
+    assert_eq!((accumulator - Field::one()).eject_value(), candidate.eject_value());
+    assert_eq!(exponent, Circuit::num_constraints());
+    candidate
+}
+
+fn generate_constraints(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Assignment::generate_constraints");
+    for exponent in EXPONENTS {
+        let _candidate = create_example_circuit(exponent);
+        let assignment = Circuit::eject_assignment_and_reset();
+
+        group.throughput(Throughput::Elements(assignment.num_constraints()));
+        group.bench_with_input(BenchmarkId::from_parameter(exponent), &assignment, |b, assignment| {
+            b.iter_batched(
+                TestConstraintSystem::new,
+                |mut cs| assignment.generate_constraints(&mut cs).unwrap(),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+/// Run once without and once with `--features parallel` to compare the serial and parallel conversion.
+fn generate_constraints_large(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Assignment::generate_constraints");
+    let _candidate = create_example_circuit(LARGE_EXPONENT);
+    let assignment = Circuit::eject_assignment_and_reset();
+
+    let mode = if cfg!(feature = "parallel") { "parallel" } else { "serial" };
+    group.throughput(Throughput::Elements(assignment.num_constraints()));
+    group.bench_with_input(BenchmarkId::new(mode, LARGE_EXPONENT), &assignment, |b, assignment| {
+        b.iter_batched(
+            RecordingConstraintSystem::new,
+            |mut cs| assignment.generate_constraints(&mut cs).unwrap(),
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+fn from_r1cs(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Assignment::from");
+    for exponent in EXPONENTS {
+        group.throughput(Throughput::Elements(exponent));
+        group.bench_function(BenchmarkId::from_parameter(exponent), |b| {
+            b.iter_batched(
+                || {
+                    let _candidate = create_example_circuit(exponent);
+                    Circuit::eject_r1cs_and_reset()
+                },
+                Assignment::from,
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn num_nonzeros(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Assignment::num_nonzeros");
+    for exponent in EXPONENTS {
+        let _candidate = create_example_circuit(exponent);
+        let assignment = Circuit::eject_assignment_and_reset();
+
+        group.throughput(Throughput::Elements(assignment.num_constraints()));
+        group.bench_with_input(BenchmarkId::new("serial", exponent), &assignment, |b, assignment| {
+            b.iter(|| assignment.num_nonzeros())
+        });
+        group.bench_with_input(BenchmarkId::new("rayon", exponent), &assignment, |b, assignment| {
+            b.iter(|| assignment.rayon_num_nonzeros())
+        });
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = assignment;
+    config = Criterion::default().sample_size(10);
+    targets = generate_constraints, generate_constraints_large, from_r1cs, num_nonzeros
+}
+
+criterion_main!(assignment);
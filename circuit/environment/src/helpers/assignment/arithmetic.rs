@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::ops::{Add, Sub};
+
+impl<F: Field> AssignmentLC<F> {
+    /// Returns the linear combination, with its constant and each of its coefficients multiplied by the given scalar.
+    /// Any term whose resulting coefficient is zero is removed.
+    pub fn scale(&self, scalar: F) -> Self {
+        Self {
+            constant: self.constant * scalar,
+            terms: self
+                .terms
+                .iter()
+                .map(|(variable, coefficient)| (variable.clone(), *coefficient * scalar))
+                .filter(|(_, coefficient)| !coefficient.is_zero())
+                .collect(),
+        }
+    }
+
+    /// Returns the sum of this linear combination and the given linear combination, with the terms of the given
+    /// linear combination multiplied by the given sign. The terms of this linear combination keep their order,
+    /// followed by the new terms of the given linear combination, and any term that sums to zero is removed.
+    fn merge(&self, other: &Self, sign: F) -> Self {
+        let mut terms = self.terms.clone();
+        for (variable, coefficient) in other.terms.iter() {
+            *terms.entry(variable.clone()).or_insert_with(F::zero) += sign * coefficient;
+        }
+        terms.retain(|_, coefficient| !coefficient.is_zero());
+
+        Self { constant: self.constant + sign * other.constant, terms }
+    }
+}
+
+impl<F: Field> Add<&AssignmentLC<F>> for &AssignmentLC<F> {
+    type Output = AssignmentLC<F>;
+
+    /// Returns the sum of the linear combinations, removing any term that sums to zero.
+    fn add(self, other: &AssignmentLC<F>) -> Self::Output {
+        self.merge(other, F::one())
+    }
+}
+
+impl<F: Field> Sub<&AssignmentLC<F>> for &AssignmentLC<F> {
+    type Output = AssignmentLC<F>;
+
+    /// Returns the difference of the linear combinations, removing any term that cancels to zero.
+    fn sub(self, other: &AssignmentLC<F>) -> Self::Output {
+        self.merge(other, -F::one())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+
+    #[test]
+    fn test_scale() {
+        let lc = AssignmentLC {
+            constant: Fr::from(5u64),
+            terms: [(AssignmentVariable::Private(0), Fr::from(2u64)), (AssignmentVariable::Public(1), Fr::zero())]
+                .into_iter()
+                .collect(),
+        };
+
+        // Ensure the constant and coefficients are scaled, and zero terms are removed.
+        let candidate = lc.scale(Fr::from(3u64));
+        assert_eq!(Fr::from(15u64), candidate.constant);
+        assert_eq!(
+            [(AssignmentVariable::Private(0), Fr::from(6u64))].into_iter().collect::<IndexMap<_, _>>(),
+            candidate.terms
+        );
+
+        // Ensure scaling by one only removes the zero terms.
+        let candidate = lc.scale(Fr::one());
+        assert_eq!(lc.constant, candidate.constant);
+        assert_eq!(1, candidate.terms.len());
+
+        // Ensure scaling by zero yields the zero linear combination.
+        let candidate = lc.scale(Fr::zero());
+        assert!(candidate.constant.is_zero());
+        assert!(candidate.terms.is_empty());
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let (x, y, z) = (AssignmentVariable::Private(0), AssignmentVariable::Private(1), AssignmentVariable::Public(1));
+        let a = AssignmentLC {
+            constant: Fr::from(5u64),
+            terms: [(x.clone(), Fr::from(2u64)), (y.clone(), Fr::one())].into_iter().collect(),
+        };
+        let b = AssignmentLC {
+            constant: Fr::from(7u64),
+            terms: [(z.clone(), Fr::from(3u64)), (y.clone(), -Fr::one())].into_iter().collect(),
+        };
+
+        // Ensure the sum merges the terms in order, removing the terms that cancel.
+        let sum = &a + &b;
+        assert_eq!(Fr::from(12u64), sum.constant);
+        assert_eq!(vec![(&x, &Fr::from(2u64)), (&z, &Fr::from(3u64))], sum.terms.iter().collect::<Vec<_>>());
+
+        // Ensure the difference is the inverse of the sum.
+        assert_eq!(a, &sum - &b);
+        assert_eq!(b, &sum - &a);
+        assert!(&a - &a == AssignmentLC { constant: Fr::zero(), terms: Default::default() });
+
+        // Ensure zero coefficients are pruned.
+        let mut c = a.clone();
+        c.terms.insert(z, Fr::zero());
+        assert_ne!(c, &(&c + &b) - &b);
+        assert_eq!(a, &(&c + &b) - &b);
+    }
+}
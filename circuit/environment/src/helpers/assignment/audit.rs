@@ -0,0 +1,355 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_utilities::BigInteger;
+
+/// The thresholds above which [`Assignment::audit`] reports a warning.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AuditThresholds {
+    /// The maximum number of private variables that are not referenced by any constraint.
+    pub max_dead_variables: usize,
+    /// The maximum number of constraints that hold for any witness (see [`Assignment::detect_trivial_constraints`]).
+    pub max_trivial_constraints: usize,
+    /// The maximum number of disconnected components in the constraint graph.
+    pub max_components: usize,
+    /// The maximum number of lookup tables that are not referenced by any lookup constraint.
+    pub max_unused_tables: usize,
+    /// The maximum number of public variables that are not referenced by any constraint.
+    pub max_underconstrained_publics: usize,
+    /// The maximum number of bits in the magnitude of a signed coefficient.
+    pub max_coefficient_bits: u32,
+}
+
+impl Default for AuditThresholds {
+    /// Returns the strictest thresholds, except for coefficients, which may be up to 64 bits.
+    fn default() -> Self {
+        Self {
+            max_dead_variables: 0,
+            max_trivial_constraints: 0,
+            max_components: 1,
+            max_unused_tables: 0,
+            max_underconstrained_publics: 0,
+            max_coefficient_bits: 64,
+        }
+    }
+}
+
+/// A warning reported by [`Assignment::audit`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AuditWarning {
+    /// The private variables that are not referenced by any constraint.
+    DeadVariables(Vec<Index>),
+    /// The indices of the constraints that hold for any witness (see [`Assignment::detect_trivial_constraints`]).
+    TrivialConstraints(Vec<usize>),
+    /// The number of disconnected components in the constraint graph.
+    DisconnectedComponents(usize),
+    /// The indices of the lookup tables that are not referenced by any lookup constraint.
+    UnusedTables(Vec<usize>),
+    /// The public variables that are not referenced by any constraint.
+    UnderconstrainedPublics(Vec<Index>),
+    /// The number of bits in the magnitude of the largest signed coefficient.
+    HugeCoefficient(u32),
+}
+
+impl<F: Field> Assignment<F> {
+    /// Returns the indices of the linear constraints in which a variable cancels itself out.
+    ///
+    /// A constraint is linear if `A` or `B` is a constant `k`, in which case it enforces `k * L - C == 0`,
+    /// where `L` is the other side. If a variable appears in both `L` and `C`, such that its coefficient
+    /// in `k * L - C` is zero, then the variable is not constrained by this constraint, which may indicate
+    /// a redundant or under-constrained circuit. This is a heuristic, and may report intentional cancellations.
+    pub fn self_canceling_constraints(&self) -> Vec<usize> {
+        self.constraints
+            .iter()
+            .enumerate()
+            .filter(|(_, (a, b, c))| {
+                // Determine the linear side, and the constant it is scaled by.
+                let (lc, scalar) = match (a.is_effectively_constant(), b.is_effectively_constant()) {
+                    (_, true) => (a, b.constant),
+                    (true, false) => (b, a.constant),
+                    (false, false) => return false,
+                };
+                // Check if any variable in the linear side cancels with the same variable in `C`.
+                lc.terms.iter().any(|(variable, coefficient)| match c.terms.get(variable) {
+                    Some(c_coefficient) => !c_coefficient.is_zero() && *coefficient * scalar == *c_coefficient,
+                    None => false,
+                })
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Returns the indices of the constraints of the form `0 * B == 0` or `A * 0 == 0`, which hold for any witness.
+    ///
+    /// A linear combination is zero if its constant and all of its coefficients are zero. Such constraints
+    /// only add to the size of the circuit, and may be removed without changing its satisfiability.
+    pub fn detect_trivial_constraints(&self) -> Vec<usize> {
+        let is_zero = |lc: &AssignmentLC<F>| lc.constant.is_zero() && lc.is_effectively_constant();
+        self.constraints
+            .iter()
+            .enumerate()
+            .filter(|(_, (a, b, c))| (is_zero(a) || is_zero(b)) && is_zero(c))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Returns the indices of the constraints and lookup constraints in which the given variable appears
+    /// with a nonzero coefficient, in any of `A`, `B`, or `C`.
+    ///
+    /// The indices follow the order of synthesis, so the `i`-th lookup constraint has index `num_constraints + i`.
+    pub fn constraint_referencing(&self, variable: &AssignmentVariable<F>) -> Vec<usize> {
+        let references = |lc: &AssignmentLC<F>| !lc.get_coefficient(variable).is_zero();
+        self.constraints
+            .iter()
+            .map(|(a, b, c)| [a, b, c])
+            .chain(self.lookup_constraints.iter().map(|(a, b, c, _)| [a, b, c]))
+            .enumerate()
+            .filter(|(_, lcs)| lcs.iter().any(|lc| references(lc)))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Returns the variables that appear with a nonzero coefficient in a constraint or lookup constraint.
+    fn referenced_variables(&self) -> IndexSet<&AssignmentVariable<F>> {
+        self.linear_combinations()
+            .flat_map(|lc| lc.terms.iter())
+            .filter(|(_, coefficient)| !coefficient.is_zero())
+            .map(|(variable, _)| variable)
+            .collect()
+    }
+
+    /// Returns the number of connected components in the graph of variables, in which two variables
+    /// are connected if they appear in the same constraint. The first public variable is excluded,
+    /// as it is the constant wire of the constraint system.
+    fn num_components(&self) -> usize {
+        // Initialize a union-find over the wires.
+        let num_wires = 1 + self.public.len() + self.private.len();
+        let mut parents = (0..num_wires).collect::<Vec<_>>();
+        fn find(parents: &mut [usize], mut wire: usize) -> usize {
+            while parents[wire] != wire {
+                parents[wire] = parents[parents[wire]];
+                wire = parents[wire];
+            }
+            wire
+        }
+
+        let is_variable = |variable: &AssignmentVariable<F>| {
+            !matches!(variable, AssignmentVariable::Constant(_) | AssignmentVariable::Public(0))
+        };
+        let constraints = self
+            .constraints
+            .iter()
+            .map(|(a, b, c)| [a, b, c])
+            .chain(self.lookup_constraints.iter().map(|(a, b, c, _)| [a, b, c]));
+
+        let mut wires = IndexSet::new();
+        for lcs in constraints {
+            let mut constraint_wires = lcs
+                .into_iter()
+                .flat_map(|lc| lc.terms.iter())
+                .filter(|(variable, coefficient)| is_variable(variable) && !coefficient.is_zero())
+                .map(|(variable, _)| self.wire(variable))
+                .filter(|wire| *wire < num_wires);
+            if let Some(first) = constraint_wires.next() {
+                wires.insert(first);
+                for wire in constraint_wires {
+                    wires.insert(wire);
+                    let (root, other_root) = (find(&mut parents, first), find(&mut parents, wire));
+                    parents[other_root] = root;
+                }
+            }
+        }
+        wires.into_iter().map(|wire| find(&mut parents, wire)).collect::<IndexSet<_>>().len()
+    }
+}
+
+impl<F: PrimeField> Assignment<F> {
+    /// Returns the warnings of a suite of checks on the assignment, for each check that exceeds its threshold.
+    ///
+    /// This reports dead private variables, trivial constraints, disconnected components, unused lookup tables,
+    /// underconstrained public variables, and huge coefficients. These checks are heuristics for flaws in a circuit,
+    /// and a warning does not necessarily indicate a bug.
+    pub fn audit(&self, thresholds: AuditThresholds) -> Vec<AuditWarning> {
+        let mut warnings = Vec::new();
+        let referenced = self.referenced_variables();
+
+        // Check for private variables that are not referenced.
+        let dead = self.private.keys().filter(|index| !referenced.contains(&AssignmentVariable::Private(**index)));
+        let dead = dead.copied().collect::<Vec<_>>();
+        if dead.len() > thresholds.max_dead_variables {
+            warnings.push(AuditWarning::DeadVariables(dead));
+        }
+
+        // Check for constraints that hold for any witness.
+        let trivial = self.detect_trivial_constraints();
+        if trivial.len() > thresholds.max_trivial_constraints {
+            warnings.push(AuditWarning::TrivialConstraints(trivial));
+        }
+
+        // Check for disconnected components.
+        let num_components = self.num_components();
+        if num_components > thresholds.max_components {
+            warnings.push(AuditWarning::DisconnectedComponents(num_components));
+        }
+
+        // Check for lookup tables that are not referenced.
+        let unused = self
+            .lookup_table_usage()
+            .into_iter()
+            .filter(|(_, count)| *count == 0)
+            .map(|(table_index, _)| table_index)
+            .collect::<Vec<_>>();
+        if unused.len() > thresholds.max_unused_tables {
+            warnings.push(AuditWarning::UnusedTables(unused));
+        }
+
+        // Check for public variables that are not referenced, excluding the constant wire.
+        let underconstrained = self
+            .public
+            .keys()
+            .filter(|index| **index != 0 && !referenced.contains(&AssignmentVariable::Public(**index)))
+            .copied()
+            .collect::<Vec<_>>();
+        if underconstrained.len() > thresholds.max_underconstrained_publics {
+            warnings.push(AuditWarning::UnderconstrainedPublics(underconstrained));
+        }
+
+        // Check for huge coefficients.
+        let num_bits = self.max_signed_coefficient().0.to_bigint().num_bits();
+        if num_bits > thresholds.max_coefficient_bits {
+            warnings.push(AuditWarning::HugeCoefficient(num_bits));
+        }
+
+        warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+
+    #[test]
+    fn test_self_canceling_constraints() {
+        let mut assignment = super::super::tests::create_example_assignment::<Fr>();
+        assert!(assignment.self_canceling_constraints().is_empty());
+
+        // Enforce `(x + x^2) * 1 == x + x^3`, in which `x` cancels out.
+        let one = Fr::one();
+        let lc = |constant, terms: &[(AssignmentVariable<Fr>, Fr)]| AssignmentLC {
+            constant,
+            terms: terms.iter().cloned().collect(),
+        };
+        assignment.constraints.push((
+            lc(Fr::zero(), &[(AssignmentVariable::Private(0), one), (AssignmentVariable::Private(1), one)]),
+            lc(one, &[]),
+            lc(Fr::zero(), &[(AssignmentVariable::Private(0), one), (AssignmentVariable::Private(2), one)]),
+        ));
+        assert_eq!(vec![3], assignment.self_canceling_constraints());
+    }
+
+    #[test]
+    fn test_detect_trivial_constraints() {
+        let mut assignment = super::super::tests::create_example_assignment::<Fr>();
+        assert!(assignment.detect_trivial_constraints().is_empty());
+
+        let (zero, one) = (Fr::zero(), Fr::one());
+        let lc = |constant, terms: &[(AssignmentVariable<Fr>, Fr)]| AssignmentLC {
+            constant,
+            terms: terms.iter().cloned().collect(),
+        };
+        let x = AssignmentVariable::Private(0);
+
+        // Enforce `0 * x == 0` and `x * (0 * x) == 0`, which are trivial.
+        assignment.constraints.push((lc(zero, &[]), lc(zero, &[(x.clone(), one)]), lc(zero, &[])));
+        assignment.constraints.push((lc(zero, &[(x.clone(), one)]), lc(zero, &[(x.clone(), zero)]), lc(zero, &[])));
+        // Enforce `0 * x == 1` and `1 * x == 0`, which are not trivial.
+        assignment.constraints.push((lc(zero, &[]), lc(zero, &[(x.clone(), one)]), lc(one, &[])));
+        assignment.constraints.push((lc(one, &[]), lc(zero, &[(x, one)]), lc(zero, &[])));
+        assert_eq!(vec![3, 4], assignment.detect_trivial_constraints());
+    }
+
+    #[test]
+    fn test_constraint_referencing() {
+        let mut assignment = super::super::tests::create_example_assignment::<Fr>();
+        assert_eq!(vec![0, 1, 2], assignment.constraint_referencing(&AssignmentVariable::Private(0)));
+        assert_eq!(vec![0, 1], assignment.constraint_referencing(&AssignmentVariable::Private(1)));
+        assert_eq!(vec![2], assignment.constraint_referencing(&AssignmentVariable::Public(1)));
+        assert!(assignment.constraint_referencing(&AssignmentVariable::Public(0)).is_empty());
+
+        // Ensure a variable with a zero coefficient is not referenced.
+        assignment.constraints[2].2.terms.insert(AssignmentVariable::Public(0), Fr::zero());
+        assert!(assignment.constraint_referencing(&AssignmentVariable::Public(0)).is_empty());
+
+        // Ensure the lookup constraints follow the constraints.
+        let (a, b, c) = assignment.constraints[2].clone();
+        assignment.lookup_constraints.push((a, b, c, 0));
+        assert_eq!(vec![2, 3], assignment.constraint_referencing(&AssignmentVariable::Public(1)));
+    }
+
+    #[test]
+    fn test_audit() {
+        let mut assignment = super::super::tests::create_example_assignment::<Fr>();
+        assert!(assignment.audit(AuditThresholds::default()).is_empty());
+
+        let one = Fr::one();
+        let lc = |constant, terms: &[(AssignmentVariable<Fr>, Fr)]| AssignmentLC {
+            constant,
+            terms: terms.iter().cloned().collect(),
+        };
+        // Add a dead private variable, and a public variable that is not referenced.
+        assignment.private.insert(3, Fr::from(4u64));
+        assignment.public.insert(2, Fr::from(7u64));
+        // Add a trivial constraint `0 * 1 == 0`.
+        assignment.constraints.push((lc(Fr::zero(), &[]), lc(one, &[]), lc(Fr::zero(), &[])));
+        // Add a disconnected component `y * y == y`, with a huge coefficient.
+        let huge = Fr::from(1u128 << 100);
+        let y = AssignmentVariable::Private(4);
+        assignment.private.insert(4, one);
+        assignment.constraints.push((
+            lc(Fr::zero(), &[(y.clone(), huge)]),
+            lc(Fr::zero(), &[(y.clone(), one)]),
+            lc(Fr::zero(), &[(y, huge)]),
+        ));
+        // Add a lookup table that is not referenced.
+        assignment.tables.push(LookupTable::default());
+        assert!(assignment.is_satisfied());
+
+        assert_eq!(
+            vec![
+                AuditWarning::DeadVariables(vec![3]),
+                AuditWarning::TrivialConstraints(vec![3]),
+                AuditWarning::DisconnectedComponents(2),
+                AuditWarning::UnusedTables(vec![0]),
+                AuditWarning::UnderconstrainedPublics(vec![2]),
+                AuditWarning::HugeCoefficient(101),
+            ],
+            assignment.audit(AuditThresholds::default())
+        );
+
+        // Ensure relaxed thresholds suppress the warnings.
+        let thresholds = AuditThresholds {
+            max_dead_variables: 1,
+            max_trivial_constraints: 1,
+            max_components: 2,
+            max_unused_tables: 1,
+            max_underconstrained_publics: 1,
+            max_coefficient_bits: 101,
+        };
+        assert!(assignment.audit(thresholds).is_empty());
+    }
+}
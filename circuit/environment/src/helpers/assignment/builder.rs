@@ -0,0 +1,253 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_algorithms::r1cs::SynthesisError;
+
+impl<F: Field> Assignment<F> {
+    /// Initializes a new assignment, with the constant `1` allocated as the first public variable.
+    pub fn new() -> Self {
+        Self {
+            public: [(0, F::one())].into_iter().collect(),
+            private: Default::default(),
+            constraints: Default::default(),
+            tables: Default::default(),
+            lookup_constraints: Default::default(),
+            circuit_version: None,
+        }
+    }
+
+    /// Initializes a new assignment as in [`Assignment::new`], with capacity for at least the given number of
+    /// public variables (including the constant `1`), private variables, and constraints.
+    pub fn with_capacity(num_public: usize, num_private: usize, num_constraints: usize) -> Self {
+        let mut assignment = Self::new();
+        assignment.reserve(num_public.saturating_sub(1), num_private, num_constraints);
+        assignment
+    }
+
+    /// Reserves capacity for at least the given number of additional public variables, private variables,
+    /// and constraints, to avoid reallocations when allocating variables and appending constraints.
+    pub fn reserve(&mut self, additional_public: usize, additional_private: usize, additional_constraints: usize) {
+        self.public.reserve(additional_public);
+        self.private.reserve(additional_private);
+        self.constraints.reserve(additional_constraints);
+    }
+
+    /// Allocates a public variable with the given value, and returns its index.
+    pub fn allocate_public(&mut self, value: F) -> Index {
+        let index = self.public.len() as Index;
+        self.public.insert(index, value);
+        index
+    }
+
+    /// Allocates a private variable with the given value, and returns its index.
+    pub fn allocate_private(&mut self, value: F) -> Index {
+        let index = self.private.len() as Index;
+        self.private.insert(index, value);
+        index
+    }
+
+    /// Appends the constraint `(a * b) == c` to the assignment, without checking that it is satisfied.
+    pub fn append_constraint(&mut self, a: AssignmentLC<F>, b: AssignmentLC<F>, c: AssignmentLC<F>) {
+        self.constraints.push((a, b, c));
+    }
+
+    /// Appends the constraint `(a * b) == c` to the assignment, if it is satisfied by the allocated variables.
+    /// Otherwise, returns an error with the evaluations of `a`, `b`, and `c`, and leaves the assignment unchanged.
+    pub fn add_constraint_validated(
+        &mut self,
+        a: AssignmentLC<F>,
+        b: AssignmentLC<F>,
+        c: AssignmentLC<F>,
+    ) -> Result<(), SynthesisError> {
+        // Retrieve the index of the new constraint.
+        let index = self.constraints.len();
+        // Evaluate the linear combinations under the allocated variables.
+        let (a_value, b_value, c_value) = match (self.evaluate_lc(&a), self.evaluate_lc(&b), self.evaluate_lc(&c)) {
+            (Some(a_value), Some(b_value), Some(c_value)) => (a_value, b_value, c_value),
+            _ => {
+                return Err(SynthesisError::AssignmentMismatch(format!(
+                    "Constraint {index} references a variable that has not been allocated"
+                )));
+            }
+        };
+        // Ensure the constraint is satisfied.
+        if a_value * b_value != c_value {
+            return Err(SynthesisError::AssignmentMismatch(format!(
+                "Constraint {index} is unsatisfied: ({a_value} * {b_value}) != {c_value}"
+            )));
+        }
+        // Append the constraint.
+        self.constraints.push((a, b, c));
+        Ok(())
+    }
+
+    /// Initializes an assignment from its public variables, private variables, constraints, lookup tables,
+    /// and lookup constraints, without a circuit version.
+    /// Returns an error if the parts do not satisfy the invariants checked by [`Assignment::validate`],
+    /// such as the indices of the variables being contiguous from zero, which synthesis relies on.
+    pub fn from_parts(
+        public: IndexMap<Index, F>,
+        private: IndexMap<Index, F>,
+        constraints: Vec<(AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>)>,
+        tables: Vec<LookupTable<F>>,
+        lookup_constraints: Vec<(AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>, usize)>,
+    ) -> Result<Self, SynthesisError> {
+        let assignment = Self { public, private, constraints, tables, lookup_constraints, circuit_version: None };
+        assignment.validate()?;
+        Ok(assignment)
+    }
+
+    /// Returns the public variables, private variables, constraints, lookup tables, and lookup constraints
+    /// of the assignment, in the order taken by [`Assignment::from_parts`]. The circuit version is dropped.
+    #[allow(clippy::type_complexity)]
+    pub fn into_parts(
+        self,
+    ) -> (
+        IndexMap<Index, F>,
+        IndexMap<Index, F>,
+        Vec<(AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>)>,
+        Vec<LookupTable<F>>,
+        Vec<(AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>, usize)>,
+    ) {
+        (self.public, self.private, self.constraints, self.tables, self.lookup_constraints)
+    }
+}
+
+impl<F: Field> Default for Assignment<F> {
+    /// Initializes a new assignment, with the constant `1` allocated as the first public variable.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::r1cs::{ConstraintSynthesizer, TestConstraintSystem};
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+
+    #[test]
+    fn test_append_constraint() {
+        let lc = |variable| AssignmentLC { constant: Fr::zero(), terms: [(variable, Fr::one())].into_iter().collect() };
+
+        // Construct the example assignment, computing `x^3 + x + 5 == 35`.
+        let mut assignment = Assignment::<Fr>::new();
+        let output = assignment.allocate_public(Fr::from(35u64));
+        let x = assignment.allocate_private(Fr::from(3u64));
+        let x2 = assignment.allocate_private(Fr::from(9u64));
+        let x3 = assignment.allocate_private(Fr::from(27u64));
+        assert_eq!((1, 0, 1, 2), (output, x, x2, x3));
+
+        let (x, x2, x3) =
+            (AssignmentVariable::Private(x), AssignmentVariable::Private(x2), AssignmentVariable::Private(x3));
+        assignment.append_constraint(lc(x.clone()), lc(x.clone()), lc(x2.clone()));
+        assignment.append_constraint(lc(x2), lc(x.clone()), lc(x3.clone()));
+        let sum =
+            AssignmentLC { constant: Fr::from(5u64), terms: [(x3, Fr::one()), (x, Fr::one())].into_iter().collect() };
+        let one = AssignmentLC { constant: Fr::one(), terms: Default::default() };
+        assignment.append_constraint(sum, one, lc(AssignmentVariable::Public(output)));
+
+        assert_eq!(super::super::tests::create_example_assignment::<Fr>(), assignment);
+        assert!(assignment.is_satisfied());
+
+        // Ensure the assignment synthesizes.
+        let mut cs = TestConstraintSystem::new();
+        assignment.generate_constraints(&mut cs).unwrap();
+        assert!(cs.is_satisfied());
+        assert_eq!(3, cs.num_constraints());
+    }
+
+    #[test]
+    fn test_with_capacity() {
+        let mut assignment = Assignment::<Fr>::with_capacity(2, 3, 3);
+        assert_eq!(Assignment::new(), assignment);
+        assert!(assignment.public.capacity() >= 2);
+        assert!(assignment.private.capacity() >= 3);
+        assert!(assignment.constraints.capacity() >= 3);
+
+        // Ensure reserving accounts for the existing variables and constraints.
+        let expected = super::super::tests::create_example_assignment::<Fr>();
+        assignment = expected.clone();
+        assignment.reserve(4, 5, 6);
+        assert_eq!(expected, assignment);
+        assert!(assignment.public.capacity() >= 6);
+        assert!(assignment.private.capacity() >= 8);
+        assert!(assignment.constraints.capacity() >= 9);
+    }
+
+    #[test]
+    fn test_add_constraint_validated() {
+        let mut assignment = super::super::tests::create_example_assignment::<Fr>();
+        let num_constraints = assignment.num_constraints();
+
+        let lc = |variable| AssignmentLC { constant: Fr::zero(), terms: [(variable, Fr::one())].into_iter().collect() };
+
+        // Enforce `x * x == x^2`, which is satisfied.
+        let (a, b, c) = (
+            lc(AssignmentVariable::Private(0)),
+            lc(AssignmentVariable::Private(0)),
+            lc(AssignmentVariable::Private(1)),
+        );
+        assignment.add_constraint_validated(a, b, c).unwrap();
+        assert_eq!(num_constraints + 1, assignment.num_constraints());
+
+        // Enforce `x * x == x^3`, which is unsatisfied.
+        let (a, b, c) = (
+            lc(AssignmentVariable::Private(0)),
+            lc(AssignmentVariable::Private(0)),
+            lc(AssignmentVariable::Private(2)),
+        );
+        let error = assignment.add_constraint_validated(a, b, c).unwrap_err();
+        assert_eq!("Assignment mismatch: Constraint 4 is unsatisfied: (3 * 3) != 27", error.to_string());
+        assert_eq!(num_constraints + 1, assignment.num_constraints());
+
+        // Enforce `x * x == y`, where `y` is unallocated.
+        let (a, b, c) = (
+            lc(AssignmentVariable::Private(0)),
+            lc(AssignmentVariable::Private(0)),
+            lc(AssignmentVariable::Private(3)),
+        );
+        assert!(assignment.add_constraint_validated(a, b, c).is_err());
+        assert_eq!(num_constraints + 1, assignment.num_constraints());
+    }
+
+    #[test]
+    fn test_parts() {
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+
+        // Ensure the parts round-trip.
+        let (public, private, constraints, tables, lookup_constraints) = assignment.clone().into_parts();
+        let other = Assignment::from_parts(public, private, constraints, tables, lookup_constraints).unwrap();
+        assert_eq!(assignment, other);
+
+        // Ensure non-contiguous indices are rejected.
+        let (public, mut private, constraints, tables, lookup_constraints) = assignment.clone().into_parts();
+        let value = private.swap_remove(&2).unwrap();
+        private.insert(3, value);
+        let error = Assignment::from_parts(public, private, constraints, tables, lookup_constraints).unwrap_err();
+        assert_eq!(
+            "Assignment mismatch: Private variable 2 has index 3, but the indices must be contiguous from 0 and in order",
+            error.to_string()
+        );
+
+        // Ensure a missing lookup table is rejected.
+        let (public, private, constraints, tables, mut lookup_constraints) = assignment.into_parts();
+        let (a, b, c) = constraints[0].clone();
+        lookup_constraints.push((a, b, c, 0));
+        assert!(Assignment::from_parts(public, private, constraints, tables, lookup_constraints).is_err());
+    }
+}
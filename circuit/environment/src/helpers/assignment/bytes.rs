@@ -0,0 +1,383 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_utilities::{
+    error,
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    ToBytes,
+};
+
+/// The magic byte that prefixes a serialized assignment.
+const ASSIGNMENT_MAGIC: u8 = 0xA5;
+/// The magic byte that prefixes a serialized assignment without the contents of its lookup tables.
+const ASSIGNMENT_WITHOUT_TABLES_MAGIC: u8 = 0xA6;
+/// The version of the assignment serialization format.
+const ASSIGNMENT_VERSION: u16 = 1;
+
+impl<F: Field> FromBytes for AssignmentVariable<F> {
+    /// Reads the assignment variable from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let variant = u8::read_le(&mut reader)?;
+        match variant {
+            0 => Ok(Self::Constant(F::read_le(&mut reader)?)),
+            1 => Ok(Self::Public(Index::read_le(&mut reader)?)),
+            2 => Ok(Self::Private(Index::read_le(&mut reader)?)),
+            3.. => Err(error(format!("Failed to decode assignment variable variant {variant}"))),
+        }
+    }
+}
+
+impl<F: Field> ToBytes for AssignmentVariable<F> {
+    /// Writes the assignment variable to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Constant(value) => {
+                0u8.write_le(&mut writer)?;
+                value.write_le(&mut writer)
+            }
+            Self::Public(index) => {
+                1u8.write_le(&mut writer)?;
+                index.write_le(&mut writer)
+            }
+            Self::Private(index) => {
+                2u8.write_le(&mut writer)?;
+                index.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+impl<F: Field> FromBytes for AssignmentLC<F> {
+    /// Reads the linear combination from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let constant = F::read_le(&mut reader)?;
+        let num_terms = u64::read_le(&mut reader)?;
+        let mut terms = IndexMap::new();
+        for _ in 0..num_terms {
+            let variable = AssignmentVariable::read_le(&mut reader)?;
+            let coefficient = F::read_le(&mut reader)?;
+            if terms.insert(variable, coefficient).is_some() {
+                return Err(error("Failed to decode a linear combination with a duplicate term"));
+            }
+        }
+        Ok(Self { constant, terms })
+    }
+}
+
+impl<F: Field> ToBytes for AssignmentLC<F> {
+    /// Writes the linear combination to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.constant.write_le(&mut writer)?;
+        (self.terms.len() as u64).write_le(&mut writer)?;
+        for (variable, coefficient) in &self.terms {
+            variable.write_le(&mut writer)?;
+            coefficient.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads the variables from a buffer, ensuring their indices are in order, starting from zero.
+fn read_variables<F: Field, R: Read>(mut reader: R, mode: Mode) -> IoResult<IndexMap<Index, F>> {
+    let num_variables = u64::read_le(&mut reader)?;
+    let mut variables = IndexMap::new();
+    for expected_index in 0..num_variables {
+        let index = Index::read_le(&mut reader)?;
+        if index != expected_index {
+            return Err(error(format!("Expected {mode} variable {expected_index}, found {mode} variable {index}")));
+        }
+        variables.insert(index, F::read_le(&mut reader)?);
+    }
+    Ok(variables)
+}
+
+/// Writes the variables to a buffer, in index order.
+fn write_variables<F: Field, W: Write>(variables: &IndexMap<Index, F>, mut writer: W, mode: Mode) -> IoResult<()> {
+    (variables.len() as u64).write_le(&mut writer)?;
+    for index in 0..variables.len() as u64 {
+        match variables.get(&index) {
+            Some(value) => {
+                index.write_le(&mut writer)?;
+                value.write_le(&mut writer)?;
+            }
+            None => return Err(error(format!("Missing {mode} variable {index}"))),
+        }
+    }
+    Ok(())
+}
+
+/// Reads a linear combination triple from a buffer.
+fn read_triple<F: Field, R: Read>(mut reader: R) -> IoResult<(AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>)> {
+    Ok((AssignmentLC::read_le(&mut reader)?, AssignmentLC::read_le(&mut reader)?, AssignmentLC::read_le(&mut reader)?))
+}
+
+impl<F: Field> Assignment<F> {
+    /// Writes the assignment to a buffer, without the contents of the lookup tables.
+    /// Only the number of tables and the number of entries in each table are written, so that
+    /// the tables may be reattached with [`Assignment::from_bytes_with_external_tables`].
+    pub fn to_bytes_without_tables<W: Write>(&self, writer: W) -> Result<()> {
+        Ok(self.write_le_internal(writer, false)?)
+    }
+
+    /// Reads an assignment written with [`Assignment::to_bytes_without_tables`], and reattaches the given tables.
+    /// The tables must match the number of tables and the number of entries in each table of the original assignment.
+    /// Note that the arity of every lookup table is fixed by [`LookupTable`], to two inputs and one output.
+    pub fn from_bytes_with_external_tables<R: Read>(reader: R, tables: Vec<LookupTable<F>>) -> Result<Self> {
+        Ok(Self::read_le_internal(reader, Some(tables))?)
+    }
+
+    /// Reads the assignment from a buffer.
+    /// If external tables are given, the buffer is expected to only contain the shape of the tables.
+    fn read_le_internal<R: Read>(mut reader: R, external_tables: Option<Vec<LookupTable<F>>>) -> IoResult<Self> {
+        // Read the magic byte and the version.
+        let expected_magic = match external_tables {
+            Some(_) => ASSIGNMENT_WITHOUT_TABLES_MAGIC,
+            None => ASSIGNMENT_MAGIC,
+        };
+        let magic = u8::read_le(&mut reader)?;
+        if magic != expected_magic {
+            return Err(error(format!("Invalid assignment magic byte {magic:#04x}, expected {expected_magic:#04x}")));
+        }
+        let version = u16::read_le(&mut reader)?;
+        if version != ASSIGNMENT_VERSION {
+            return Err(error(format!("Unsupported assignment version {version}")));
+        }
+
+        // Read the circuit version.
+        let circuit_version = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some(u64::read_le(&mut reader)?),
+            variant => return Err(error(format!("Failed to decode circuit version variant {variant}"))),
+        };
+
+        // Read the variables.
+        let public = read_variables(&mut reader, Mode::Public)?;
+        let private = read_variables(&mut reader, Mode::Private)?;
+
+        // Read the constraints.
+        let num_constraints = u64::read_le(&mut reader)?;
+        let constraints = (0..num_constraints).map(|_| read_triple(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+
+        // Read the lookup tables, or their shape if the tables are external.
+        let num_tables = u64::read_le(&mut reader)?;
+        let tables = match external_tables {
+            Some(tables) => {
+                if tables.len() as u64 != num_tables {
+                    return Err(error(format!("Expected {num_tables} lookup tables, found {}", tables.len())));
+                }
+                for (i, table) in tables.iter().enumerate() {
+                    let num_entries = u64::read_le(&mut reader)?;
+                    if table.table.len() as u64 != num_entries {
+                        return Err(error(format!(
+                            "Expected {num_entries} entries in lookup table {i}, found {}",
+                            table.table.len()
+                        )));
+                    }
+                }
+                tables
+            }
+            None => {
+                let mut tables = Vec::new();
+                for _ in 0..num_tables {
+                    let num_entries = u64::read_le(&mut reader)?;
+                    let mut table = LookupTable::default();
+                    for _ in 0..num_entries {
+                        let key = [F::read_le(&mut reader)?, F::read_le(&mut reader)?];
+                        if table.fill(key, F::read_le(&mut reader)?).is_some() {
+                            return Err(error("Failed to decode a lookup table with a duplicate entry"));
+                        }
+                    }
+                    tables.push(table);
+                }
+                tables
+            }
+        };
+
+        // Read the lookup constraints.
+        let num_lookup_constraints = u64::read_le(&mut reader)?;
+        let mut lookup_constraints = Vec::new();
+        for _ in 0..num_lookup_constraints {
+            let (a, b, c) = read_triple(&mut reader)?;
+            let table_index = u64::read_le(&mut reader)?;
+            if table_index >= num_tables {
+                return Err(error(format!("Lookup constraint references missing table {table_index}")));
+            }
+            lookup_constraints.push((a, b, c, table_index as usize));
+        }
+
+        Ok(Self { public, private, constraints, tables, lookup_constraints, circuit_version })
+    }
+
+    /// Writes the assignment to a buffer.
+    /// If the tables are not inlined, only the shape of the tables is written.
+    fn write_le_internal<W: Write>(&self, mut writer: W, inline_tables: bool) -> IoResult<()> {
+        // Write the magic byte and the version.
+        match inline_tables {
+            true => ASSIGNMENT_MAGIC.write_le(&mut writer)?,
+            false => ASSIGNMENT_WITHOUT_TABLES_MAGIC.write_le(&mut writer)?,
+        }
+        ASSIGNMENT_VERSION.write_le(&mut writer)?;
+
+        // Write the circuit version.
+        match self.circuit_version {
+            None => 0u8.write_le(&mut writer)?,
+            Some(circuit_version) => {
+                1u8.write_le(&mut writer)?;
+                circuit_version.write_le(&mut writer)?;
+            }
+        }
+
+        // Write the variables.
+        write_variables(&self.public, &mut writer, Mode::Public)?;
+        write_variables(&self.private, &mut writer, Mode::Private)?;
+
+        // Write the constraints.
+        (self.constraints.len() as u64).write_le(&mut writer)?;
+        for (a, b, c) in &self.constraints {
+            a.write_le(&mut writer)?;
+            b.write_le(&mut writer)?;
+            c.write_le(&mut writer)?;
+        }
+
+        // Write the lookup tables, or only their shape if the tables are not inlined.
+        (self.tables.len() as u64).write_le(&mut writer)?;
+        for table in &self.tables {
+            (table.table.len() as u64).write_le(&mut writer)?;
+            if inline_tables {
+                for ([a, b], c) in &table.table {
+                    a.write_le(&mut writer)?;
+                    b.write_le(&mut writer)?;
+                    c.write_le(&mut writer)?;
+                }
+            }
+        }
+
+        // Write the lookup constraints.
+        (self.lookup_constraints.len() as u64).write_le(&mut writer)?;
+        for (a, b, c, table_index) in &self.lookup_constraints {
+            a.write_le(&mut writer)?;
+            b.write_le(&mut writer)?;
+            c.write_le(&mut writer)?;
+            (*table_index as u64).write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<F: Field> FromBytes for Assignment<F> {
+    /// Reads the assignment from a buffer.
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_internal(reader, None)
+    }
+
+    /// Reads the assignment from a byte slice, which must not have any bytes left after the assignment.
+    fn from_bytes_le(mut bytes: &[u8]) -> Result<Self> {
+        let assignment = Self::read_le(&mut bytes)?;
+        ensure!(bytes.is_empty(), "Found {} trailing bytes after the assignment", bytes.len());
+        Ok(assignment)
+    }
+}
+
+impl<F: Field> ToBytes for Assignment<F> {
+    /// Writes the assignment to a buffer.
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.write_le_internal(writer, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::r1cs::{ConstraintSynthesizer, ConstraintSystem, TestConstraintSystem};
+    use snarkvm_circuit::prelude::{Circuit, Environment};
+
+    type CurrentField = <Circuit as Environment>::BaseField;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let _candidate_output = super::super::tests::create_example_circuit::<Circuit>();
+        let expected = Circuit::eject_assignment_and_reset().with_circuit_version(1);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        let candidate = Assignment::<CurrentField>::read_le(&expected_bytes[..])?;
+        assert_eq!(format!("{expected:?}"), format!("{candidate:?}"));
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+
+        // Ensure both assignments synthesize into the same constraint system.
+        let mut expected_cs = TestConstraintSystem::new();
+        expected.generate_constraints(&mut expected_cs)?;
+        let mut candidate_cs = TestConstraintSystem::new();
+        candidate.generate_constraints(&mut candidate_cs)?;
+        assert_eq!(expected_cs.num_public_variables(), candidate_cs.num_public_variables());
+        assert_eq!(expected_cs.num_private_variables(), candidate_cs.num_private_variables());
+        assert_eq!(expected_cs.num_constraints(), candidate_cs.num_constraints());
+        assert_eq!(expected_cs.num_non_zero(), candidate_cs.num_non_zero());
+        assert!(candidate_cs.is_satisfied());
+
+        // Ensure truncated data is rejected.
+        assert!(Assignment::<CurrentField>::read_le(&expected_bytes[1..]).is_err());
+        assert!(Assignment::<CurrentField>::read_le(&expected_bytes[..expected_bytes.len() - 1]).is_err());
+
+        // Ensure trailing data is rejected from a slice.
+        assert_eq!(expected_bytes, Assignment::<CurrentField>::from_bytes_le(&expected_bytes)?.to_bytes_le()?);
+        let mut trailing_bytes = expected_bytes.clone();
+        trailing_bytes.push(0);
+        let error = Assignment::<CurrentField>::from_bytes_le(&trailing_bytes).unwrap_err();
+        assert_eq!("Found 1 trailing bytes after the assignment", error.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_rejects_reordered_variables() -> Result<()> {
+        let expected = super::super::tests::create_example_assignment::<CurrentField>();
+        let mut bytes = expected.to_bytes_le()?;
+
+        // Swap the first two private variables, which immediately follow the public variables.
+        let field_size = expected.private[0].to_bytes_le()?.len();
+        let variable_size = std::mem::size_of::<Index>() + field_size;
+        let start = 1 + 2 + 1 + 8 + expected.public.len() * variable_size + 8;
+        let (first, second) = bytes[start..start + 2 * variable_size].split_at_mut(variable_size);
+        first.swap_with_slice(second);
+
+        assert!(Assignment::<CurrentField>::read_le(&bytes[..]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_with_external_tables() -> Result<()> {
+        let expected = super::super::tests::create_example_assignment_with_lookup::<CurrentField>();
+
+        // Split the tables from the assignment.
+        let mut bytes = Vec::new();
+        expected.to_bytes_without_tables(&mut bytes)?;
+        assert!(bytes.len() < expected.to_bytes_le()?.len());
+        assert!(Assignment::<CurrentField>::read_le(&bytes[..]).is_err());
+
+        // Reattach the tables.
+        let candidate = Assignment::from_bytes_with_external_tables(&bytes[..], expected.tables.clone())?;
+        assert_eq!(expected, candidate);
+        assert!(candidate.is_satisfied());
+
+        // Ensure mismatched tables are rejected.
+        assert!(Assignment::<CurrentField>::from_bytes_with_external_tables(&bytes[..], vec![]).is_err());
+        let mut tables = expected.tables.clone();
+        tables[0].fill([CurrentField::from(2u64), CurrentField::from(4u64)], CurrentField::from(8u64));
+        assert!(Assignment::<CurrentField>::from_bytes_with_external_tables(&bytes[..], tables).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_algorithms::SNARK;
+
+use once_cell::sync::OnceCell;
+use parking_lot::RwLock;
+
+/// A slot for the proving key of one circuit, which is filled once its circuit setup completes.
+type ProvingKeySlot<S> = Arc<OnceCell<Arc<<S as SNARK>::ProvingKey>>>;
+
+/// A cache of proving keys, indexed by the structure hash of the assignment they were set up for.
+/// Witnesses of the same circuit share a structure hash, so the circuit setup only runs once per circuit.
+///
+/// The circuit setup runs outside of the lock on the cache, so a setup only blocks the callers
+/// waiting on the proving key of the same circuit, and the setups of different circuits run concurrently.
+pub struct ProvingKeyCache<S: SNARK> {
+    proving_keys: RwLock<IndexMap<[u8; 32], ProvingKeySlot<S>>>,
+}
+
+impl<S: SNARK> Default for ProvingKeyCache<S> {
+    /// Initializes an empty cache.
+    fn default() -> Self {
+        Self { proving_keys: Default::default() }
+    }
+}
+
+impl<S: SNARK> ProvingKeyCache<S> {
+    /// Initializes an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of cached proving keys, excluding those whose setup is in progress.
+    pub fn len(&self) -> usize {
+        self.proving_keys.read().values().filter(|slot| slot.get().is_some()).count()
+    }
+
+    /// Returns `true` if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the proving key for the given assignment, running the circuit setup on a cache miss.
+    pub fn get_or_setup(
+        &self,
+        assignment: &Assignment<S::ScalarField>,
+        srs: &S::UniversalSRS,
+    ) -> Result<Arc<S::ProvingKey>> {
+        let hash = assignment.structure_hash()?;
+        self.get_or_init(hash, || Ok(S::circuit_setup(srs, assignment)?.0))
+    }
+
+    /// Returns the proving key for the given structure hash, calling `init` on a cache miss.
+    ///
+    /// The lock on the cache is only held to look up or insert the slot of the hash, while `init` runs
+    /// outside of it. Concurrent callers for the same hash wait on the slot, so `init` only runs once,
+    /// unless it fails, in which case the slot is left empty for a later caller to retry.
+    fn get_or_init(&self, hash: [u8; 32], init: impl FnOnce() -> Result<S::ProvingKey>) -> Result<Arc<S::ProvingKey>> {
+        // Retrieve the slot of the hash, inserting an empty slot if it does not exist.
+        let slot = self.proving_keys.read().get(&hash).cloned();
+        let slot = match slot {
+            Some(slot) => slot,
+            None => self.proving_keys.write().entry(hash).or_default().clone(),
+        };
+        // Return the proving key, running the setup if it has not completed.
+        slot.get_or_try_init(|| Ok(Arc::new(init()?))).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::{
+        crypto_hash::PoseidonSponge,
+        snark::varuna::{ahp::AHPForR1CS, VarunaHidingMode, VarunaSNARK},
+    };
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
+
+    type FS = PoseidonSponge<Fq, 2, 1>;
+    type VarunaInst = VarunaSNARK<Bls12_377, FS, VarunaHidingMode>;
+
+    #[test]
+    fn test_get_or_setup() {
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(200, 200, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+
+        let first = super::super::tests::create_example_assignment::<Fr>();
+        let mut second = first.clone();
+        second.private.values_mut().for_each(|value| *value += *value);
+
+        let cache = ProvingKeyCache::<VarunaInst>::new();
+        assert!(cache.is_empty());
+
+        // Ensure the setup runs once for two witnesses of the same circuit.
+        let first_key = cache.get_or_setup(&first, &universal_srs).unwrap();
+        let second_key = cache.get_or_setup(&second, &universal_srs).unwrap();
+        assert!(Arc::ptr_eq(&first_key, &second_key));
+        assert_eq!(1, cache.len());
+
+        // Ensure a different circuit runs its own setup.
+        let mut third = first.clone();
+        third.constraints.pop();
+        let third_key = cache.get_or_setup(&third, &universal_srs).unwrap();
+        assert!(!Arc::ptr_eq(&first_key, &third_key));
+        assert_eq!(2, cache.len());
+    }
+
+    #[test]
+    fn test_get_or_setup_does_not_block_other_circuits() {
+        use std::sync::mpsc;
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(200, 200, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+
+        let first = super::super::tests::create_example_assignment::<Fr>();
+        let mut second = first.clone();
+        second.constraints.pop();
+
+        let cache = ProvingKeyCache::<VarunaInst>::new();
+        let first_key = cache.get_or_setup(&first, &universal_srs).unwrap();
+
+        // Start the setup of the second circuit, and hold it in progress until released.
+        let (started_sender, started) = mpsc::channel();
+        let (release, release_receiver) = mpsc::channel::<()>();
+        std::thread::scope(|scope| {
+            let (cache, universal_srs, second) = (&cache, &universal_srs, &second);
+            let setup = scope.spawn(move || {
+                cache.get_or_init(second.structure_hash().unwrap(), || {
+                    started_sender.send(()).unwrap();
+                    release_receiver.recv().unwrap();
+                    Ok(VarunaInst::circuit_setup(universal_srs, second)?.0)
+                })
+            });
+            started.recv().unwrap();
+
+            // Ensure a hit on the first circuit is served while the setup of the second is in progress.
+            assert!(Arc::ptr_eq(&first_key, &cache.get_or_setup(&first, universal_srs).unwrap()));
+            assert_eq!(1, cache.len());
+
+            release.send(()).unwrap();
+            let second_key = setup.join().unwrap().unwrap();
+            assert!(Arc::ptr_eq(&second_key, &cache.get_or_setup(second, universal_srs).unwrap()));
+        });
+        assert_eq!(2, cache.len());
+    }
+}
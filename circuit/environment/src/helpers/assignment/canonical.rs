@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<F: Field> AssignmentLC<F> {
+    /// Folds any constant terms into the constant, and removes all terms with a zero coefficient.
+    pub fn reduce(&mut self) {
+        // Fold the constant terms into the constant.
+        for (variable, coefficient) in self.terms.iter() {
+            if let AssignmentVariable::Constant(value) = variable {
+                self.constant += *value * coefficient;
+            }
+        }
+        // Remove the constant terms and the zero terms.
+        self.terms.retain(|variable, coefficient| {
+            !matches!(variable, AssignmentVariable::Constant(_)) && !coefficient.is_zero()
+        });
+    }
+
+    /// Substitutes the given variable with the given value, by removing its term and folding
+    /// `coefficient * value` into the constant. The order of the remaining terms is preserved.
+    pub fn substitute(&mut self, variable: &AssignmentVariable<F>, value: F) {
+        if let Some(coefficient) = self.terms.shift_remove(variable) {
+            self.constant += coefficient * value;
+        }
+    }
+
+    /// Sorts the terms of the linear combination by variable.
+    pub fn canonicalize(&mut self) {
+        self.terms.sort_keys();
+    }
+}
+
+impl<F: Field> Assignment<F> {
+    /// Reduces every linear combination in the assignment.
+    pub fn reduce_all(&mut self) {
+        for (a, b, c) in self.constraints.iter_mut() {
+            a.reduce();
+            b.reduce();
+            c.reduce();
+        }
+        for (a, b, c, _) in self.lookup_constraints.iter_mut() {
+            a.reduce();
+            b.reduce();
+            c.reduce();
+        }
+    }
+
+    /// Sorts the public and private variables by index.
+    pub fn sort_variables(&mut self) {
+        self.public.sort_keys();
+        self.private.sort_keys();
+    }
+
+    /// Sorts the terms of every linear combination in the assignment.
+    pub fn canonicalize(&mut self) {
+        for (a, b, c) in self.constraints.iter_mut() {
+            a.canonicalize();
+            b.canonicalize();
+            c.canonicalize();
+        }
+        for (a, b, c, _) in self.lookup_constraints.iter_mut() {
+            a.canonicalize();
+            b.canonicalize();
+            c.canonicalize();
+        }
+    }
+
+    /// Returns the canonical form of the assignment, by reducing, sorting, and canonicalizing it,
+    /// including the entries of its lookup tables (see [`Assignment::canonicalize_tables`]).
+    /// This is the recommended step before serializing an assignment, as equivalent assignments
+    /// share the same canonical form.
+    pub fn canonical_form(mut self) -> Self {
+        self.reduce_all();
+        self.sort_variables();
+        self.canonicalize();
+        self.canonicalize_tables();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::r1cs::{ConstraintSynthesizer, TestConstraintSystem};
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::Zero;
+    use snarkvm_utilities::ToBytes;
+
+    /// Returns a copy of the given assignment, with its variables and terms in reverse order,
+    /// and with a zero term added to every linear combination.
+    fn reorder(assignment: &Assignment<Fr>) -> Assignment<Fr> {
+        let reorder_lc = |lc: &AssignmentLC<Fr>| {
+            let mut terms: IndexMap<_, _> = lc.terms.iter().rev().map(|(v, c)| (v.clone(), *c)).collect();
+            terms.entry(AssignmentVariable::Private(0)).or_insert(Fr::zero());
+            AssignmentLC { constant: lc.constant, terms }
+        };
+
+        Assignment {
+            public: assignment.public.iter().rev().map(|(i, v)| (*i, *v)).collect(),
+            private: assignment.private.iter().rev().map(|(i, v)| (*i, *v)).collect(),
+            constraints: assignment
+                .constraints
+                .iter()
+                .map(|(a, b, c)| (reorder_lc(a), reorder_lc(b), reorder_lc(c)))
+                .collect(),
+            tables: assignment.tables.clone(),
+            lookup_constraints: assignment.lookup_constraints.clone(),
+            circuit_version: assignment.circuit_version,
+        }
+    }
+
+    #[test]
+    fn test_substitute() {
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+        let (x, x3) = (AssignmentVariable::Private(0), AssignmentVariable::Private(2));
+
+        // Substitute `x` and `x^3` in `x^3 + x + 5`.
+        let mut lc = assignment.constraints[2].0.clone();
+        lc.substitute(&x, assignment.private[0]);
+        assert_eq!(Fr::from(8u64), lc.constant());
+        assert_eq!(vec![&x3], lc.terms().keys().collect::<Vec<_>>());
+        assert_eq!(assignment.evaluate_lc(&assignment.constraints[2].0), assignment.evaluate_lc(&lc));
+
+        lc.substitute(&x3, assignment.private[2]);
+        assert_eq!(Some(Fr::from(35u64)), lc.as_constant());
+
+        // Ensure substituting a variable that is not a term is a no-op.
+        let expected = lc.clone();
+        lc.substitute(&x, Fr::from(7u64));
+        assert_eq!(expected, lc);
+    }
+
+    #[test]
+    fn test_canonical_form() {
+        let first = super::super::tests::create_example_assignment::<Fr>();
+        let second = reorder(&first);
+        assert_ne!(first.to_bytes_le().unwrap(), second.to_bytes_le().unwrap());
+
+        let first = first.canonical_form();
+        let second = second.canonical_form();
+        assert_eq!(first.to_bytes_le().unwrap(), second.to_bytes_le().unwrap());
+        super::super::tests::assert_systems_equivalent(&first, &second);
+
+        let mut cs = TestConstraintSystem::new();
+        first.generate_constraints(&mut cs).unwrap();
+        assert!(cs.is_satisfied());
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The number of coefficients of each kind, across the terms of the constraints and lookup constraints.
+/// The constants of the linear combinations are not counted.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CoefficientStats {
+    /// The number of coefficients that are one.
+    pub one: u64,
+    /// The number of coefficients that are minus one.
+    pub minus_one: u64,
+    /// The number of coefficients that are zero, which should not occur in a well-formed assignment.
+    pub zero: u64,
+    /// The number of coefficients of any other value.
+    pub other: u64,
+    /// The total number of terms.
+    pub total: u64,
+}
+
+impl<F: Field> AssignmentLC<F> {
+    /// Returns the linear combination over `G`, with `f` applied to the constant and to every coefficient,
+    /// and to the value of every constant variable in the terms.
+    fn map_coefficients<G: Field>(&self, f: &impl Fn(F) -> G) -> AssignmentLC<G> {
+        let terms = self
+            .terms
+            .iter()
+            .map(|(variable, coefficient)| {
+                let variable = match variable {
+                    AssignmentVariable::Constant(value) => AssignmentVariable::Constant(f(*value)),
+                    AssignmentVariable::Public(index) => AssignmentVariable::Public(*index),
+                    AssignmentVariable::Private(index) => AssignmentVariable::Private(*index),
+                };
+                (variable, f(*coefficient))
+            })
+            .collect();
+        AssignmentLC { constant: f(self.constant), terms }
+    }
+}
+
+impl<F: Field> Assignment<F> {
+    /// Returns the assignment over `G`, with `f` applied to every constant, coefficient, variable value,
+    /// and lookup table entry, while preserving the structure and the indices of the assignment.
+    ///
+    /// The caller is responsible for `f` being a morphism that preserves what the result is used for,
+    /// e.g. satisfiability. In particular, if `f` is not injective, distinct terms of a linear combination
+    /// or distinct rows of a lookup table may collide, in which case only the last of them is kept.
+    ///
+    /// The result is not tagged with a circuit version, as it is a circuit over a different field.
+    pub fn map_coefficients<G: Field>(&self, f: impl Fn(F) -> G) -> Assignment<G> {
+        let map_lc = |lc: &AssignmentLC<F>| lc.map_coefficients(&f);
+        Assignment {
+            public: self.public.iter().map(|(index, value)| (*index, f(*value))).collect(),
+            private: self.private.iter().map(|(index, value)| (*index, f(*value))).collect(),
+            constraints: self.constraints.iter().map(|(a, b, c)| (map_lc(a), map_lc(b), map_lc(c))).collect(),
+            tables: self
+                .tables
+                .iter()
+                .map(|table| {
+                    let mut mapped = LookupTable::default();
+                    for ([a, b], c) in table.table.iter() {
+                        mapped.fill([f(*a), f(*b)], f(*c));
+                    }
+                    mapped
+                })
+                .collect(),
+            lookup_constraints: self
+                .lookup_constraints
+                .iter()
+                .map(|(a, b, c, table_index)| (map_lc(a), map_lc(b), map_lc(c), *table_index))
+                .collect(),
+            circuit_version: None,
+        }
+    }
+
+    /// Returns the number of coefficients that are one, minus one, zero, or any other value, across the terms
+    /// of the constraints and lookup constraints. A circuit whose coefficients are mostly `±1` is R1CS-friendly.
+    pub fn coefficient_statistics(&self) -> CoefficientStats {
+        let (one, minus_one) = (F::one(), -F::one());
+        let mut stats = CoefficientStats::default();
+        for coefficient in self.linear_combinations().flat_map(|lc| lc.terms.values()) {
+            match coefficient {
+                coefficient if *coefficient == one => stats.one += 1,
+                coefficient if *coefficient == minus_one => stats.minus_one += 1,
+                coefficient if coefficient.is_zero() => stats.zero += 1,
+                _ => stats.other += 1,
+            }
+            stats.total += 1;
+        }
+        stats
+    }
+}
+
+impl<F: PrimeField> Assignment<F> {
+    /// Returns the magnitude of the largest coefficient in the assignment, and `true` if that coefficient is negative.
+    /// A coefficient is interpreted as negative if it is greater than `(MODULUS - 1) / 2`,
+    /// in which case its magnitude is its negation.
+    pub fn max_signed_coefficient(&self) -> (F, bool) {
+        let half = F::modulus_minus_one_div_two();
+        self.linear_combinations()
+            .flat_map(|lc| lc.terms.values().copied().chain([lc.constant]))
+            .map(|coefficient| match coefficient.to_bigint() > half {
+                true => (-coefficient, true),
+                false => (coefficient, false),
+            })
+            .max_by_key(|(magnitude, _)| magnitude.to_bigint())
+            .unwrap_or((F::zero(), false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+
+    #[test]
+    fn test_max_signed_coefficient() {
+        // The example assignment has a constant of five, and otherwise unit coefficients.
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+        assert_eq!((Fr::from(5u64), false), assignment.max_signed_coefficient());
+
+        // Enforce `(-x) * (-x) == -y`.
+        let minus_one = -Fr::one();
+        let lc = |variable| AssignmentLC { constant: Fr::zero(), terms: [(variable, minus_one)].into_iter().collect() };
+        let assignment = Assignment {
+            public: [(0, Fr::one())].into_iter().collect(),
+            private: [(0, Fr::from(2u64)), (1, -Fr::from(4u64))].into_iter().collect(),
+            constraints: vec![(
+                lc(AssignmentVariable::Private(0)),
+                lc(AssignmentVariable::Private(0)),
+                lc(AssignmentVariable::Private(1)),
+            )],
+            tables: vec![],
+            lookup_constraints: vec![],
+            circuit_version: None,
+        };
+        assert_eq!((Fr::one(), true), assignment.max_signed_coefficient());
+    }
+
+    #[test]
+    fn test_map_coefficients() {
+        use snarkvm_curves::bls12_377::Fq;
+        use snarkvm_utilities::ToBytes;
+
+        let assignment = super::super::tests::create_example_assignment_with_lookup::<Fr>().with_circuit_version(1);
+        assert!(assignment.is_satisfied());
+
+        // Ensure the identity preserves the assignment, except for its circuit version.
+        assert_eq!(
+            assignment.clone().with_circuit_version(0),
+            assignment.map_coefficients(|x| x).with_circuit_version(0)
+        );
+        assert_eq!(None, assignment.map_coefficients(|x| x).circuit_version());
+
+        // Embed the assignment into the larger base field, which preserves satisfiability for these small values.
+        let embed = |x: Fr| Fq::from_bytes_le_mod_order(&x.to_bytes_le().unwrap());
+        let embedded = assignment.map_coefficients(embed);
+        assert_eq!(assignment.num_public(), embedded.num_public());
+        assert_eq!(assignment.num_private(), embedded.num_private());
+        assert_eq!(assignment.num_nonzeros(), embedded.num_nonzeros());
+        assert_eq!(vec![Fq::one(), Fq::from(35u64)], embedded.public_input_values());
+        assert_eq!(Some(&Fq::from(27u64)), embedded.tables[0].table.get(&[Fq::from(3u64), Fq::from(9u64)]));
+        assert_eq!(Fq::from(5u64), embedded.constraints[2].0.constant);
+        assert!(embedded.validate().is_ok());
+        assert!(embedded.is_satisfied());
+
+        // Ensure the values are mapped, so that a non-morphism may break satisfiability.
+        assert!(!assignment.map_coefficients(|x| embed(x) + Fq::one()).is_satisfied());
+    }
+
+    #[test]
+    fn test_coefficient_statistics() {
+        // The example assignment has unit coefficients.
+        let mut assignment = super::super::tests::create_example_assignment::<Fr>();
+        let expected = CoefficientStats { one: 9, minus_one: 0, zero: 0, other: 0, total: 9 };
+        assert_eq!(expected, assignment.coefficient_statistics());
+
+        // Add the lookup constraint `(1 - x + 0 * y, 1 + 2x) -> 1`, whose constants are not counted.
+        let lc = |terms: &[(AssignmentVariable<Fr>, Fr)]| AssignmentLC {
+            constant: Fr::one(),
+            terms: terms.iter().cloned().collect(),
+        };
+        let (x, y) = (AssignmentVariable::Private(0), AssignmentVariable::Private(1));
+        assignment.lookup_constraints.push((
+            lc(&[(x.clone(), -Fr::one()), (y, Fr::zero())]),
+            lc(&[(x, Fr::from(2u64))]),
+            lc(&[]),
+            0,
+        ));
+        let expected = CoefficientStats { one: 9, minus_one: 1, zero: 1, other: 1, total: 12 };
+        assert_eq!(expected, assignment.coefficient_statistics());
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_algorithms::{crypto_hash::Poseidon, AlgebraicSponge};
+
+impl<F: PrimeField> Assignment<F> {
+    /// Returns a hiding Poseidon commitment to the private variables, in index order, under the given randomness.
+    ///
+    /// This is a commitment to the witness, and not a proof of knowledge of it. It does not show that
+    /// the private variables satisfy the constraints, and the randomness must be kept secret for it to be hiding.
+    pub fn private_input_commitment(&self, randomness: F) -> F {
+        let input = core::iter::once(randomness).chain(self.private_values_by_index()).collect::<Vec<_>>();
+        Poseidon::<F, 2>::setup().evaluate_with_len(&input)
+    }
+
+    /// Returns a hiding commitment to the private variables under the given randomness, squeezed from the given
+    /// algebraic sponge, such as the sponge of the Varuna Fiat-Shamir transform.
+    ///
+    /// The sponge absorbs the randomness, the number of private variables, and the private values in index order.
+    /// The public variables (including the constant `1`) are not absorbed, so the commitment only binds the witness.
+    /// As with [`Assignment::private_input_commitment`], this does not show that the witness satisfies the constraints.
+    pub fn witness_commitment<S: AlgebraicSponge<F, RATE>, const RATE: usize>(&self, randomness: F) -> F {
+        let mut sponge = S::new();
+        sponge.absorb_native_field_elements(&[randomness, F::from(self.num_private())]);
+        sponge.absorb_native_field_elements(&self.private_values_by_index());
+        sponge.squeeze_native_field_elements(1)[0]
+    }
+
+    /// Returns `true` if the given commitment opens to the private variables of the assignment
+    /// under the given randomness (see [`Assignment::witness_commitment`]).
+    pub fn verify_witness_commitment<S: AlgebraicSponge<F, RATE>, const RATE: usize>(
+        &self,
+        commitment: F,
+        randomness: F,
+    ) -> bool {
+        self.witness_commitment::<S, RATE>(randomness) == commitment
+    }
+}
+
+impl<F: Field> Assignment<F> {
+    /// Returns the values of the private variables, ordered by index.
+    pub(super) fn private_values_by_index(&self) -> Vec<F> {
+        let mut private = self.private.iter().collect::<Vec<_>>();
+        private.sort_unstable_by_key(|(index, _)| **index);
+        private.into_iter().map(|(_, value)| *value).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::One;
+
+    #[test]
+    fn test_private_input_commitment() {
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+        let randomness = Fr::from(42u64);
+
+        // Ensure the commitment is deterministic, given the same randomness.
+        let commitment = assignment.private_input_commitment(randomness);
+        assert_eq!(commitment, assignment.private_input_commitment(randomness));
+
+        // Ensure the commitment changes with the randomness.
+        assert_ne!(commitment, assignment.private_input_commitment(randomness + Fr::one()));
+
+        // Ensure the commitment changes when a private value changes.
+        let mut other = assignment.clone();
+        other.private[0] += Fr::one();
+        assert_ne!(commitment, other.private_input_commitment(randomness));
+
+        // Ensure the commitment does not change when the public values change.
+        let mut other = assignment.clone();
+        other.public[1] += Fr::one();
+        assert_eq!(commitment, other.private_input_commitment(randomness));
+    }
+
+    #[test]
+    fn test_witness_commitment() {
+        use snarkvm_algorithms::crypto_hash::PoseidonSponge;
+
+        type Sponge = PoseidonSponge<Fr, 2, 1>;
+
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+        let randomness = Fr::from(42u64);
+
+        // Ensure the commitment opens under the same randomness only.
+        let commitment = assignment.witness_commitment::<Sponge, 2>(randomness);
+        assert!(assignment.verify_witness_commitment::<Sponge, 2>(commitment, randomness));
+        assert!(!assignment.verify_witness_commitment::<Sponge, 2>(commitment, randomness + Fr::one()));
+
+        // Ensure the commitment does not open to a different witness.
+        let mut other = assignment.clone();
+        other.private[2] += Fr::one();
+        assert!(!other.verify_witness_commitment::<Sponge, 2>(commitment, randomness));
+
+        // Ensure the commitment does not depend on the public values, nor on the order of the private variables.
+        let mut other = assignment.clone();
+        other.public[1] += Fr::one();
+        other.private.reverse();
+        assert!(other.verify_witness_commitment::<Sponge, 2>(commitment, randomness));
+    }
+}
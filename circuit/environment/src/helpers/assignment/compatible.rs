@@ -0,0 +1,242 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_utilities::{io::Write, ToBytes};
+
+use blake2::Digest;
+
+/// An encoder of the structure of an assignment, as traversed by [`Assignment::encode_structure`].
+///
+/// Both structural identities of an assignment, [`Assignment::structure_hash`] and [`Assignment::hash`],
+/// are computed over this single traversal, so they always cover exactly the same data.
+pub(super) trait StructureEncoder<F: Field> {
+    /// Encodes a count, or the index of a variable or a lookup table.
+    fn encode_u64(&mut self, value: u64) -> Result<()>;
+
+    /// Encodes the tag of a term.
+    fn encode_tag(&mut self, tag: u8) -> Result<()>;
+
+    /// Encodes a field element.
+    fn encode_field(&mut self, value: &F) -> Result<()>;
+}
+
+/// Encodes the structure of an assignment into the given writer, in little-endian bytes.
+struct WriterEncoder<W: Write>(W);
+
+impl<F: Field, W: Write> StructureEncoder<F> for WriterEncoder<W> {
+    fn encode_u64(&mut self, value: u64) -> Result<()> {
+        Ok(value.write_le(&mut self.0)?)
+    }
+
+    fn encode_tag(&mut self, tag: u8) -> Result<()> {
+        Ok(tag.write_le(&mut self.0)?)
+    }
+
+    fn encode_field(&mut self, value: &F) -> Result<()> {
+        Ok(value.write_le(&mut self.0)?)
+    }
+}
+
+impl<F: Field> AssignmentLC<F> {
+    /// Encodes the constant, the number of terms, and the terms of the linear combination, sorted by variable.
+    /// Each term is a tag (`0` for a constant, `1` for a public variable, `2` for a private variable),
+    /// followed by the constant value or the variable index, followed by the coefficient.
+    fn encode_structure(&self, encoder: &mut impl StructureEncoder<F>) -> Result<()> {
+        encoder.encode_field(&self.constant)?;
+        let mut terms = self.terms.iter().collect::<Vec<_>>();
+        terms.sort_unstable();
+        encoder.encode_u64(terms.len() as u64)?;
+        for (variable, coefficient) in terms {
+            match variable {
+                AssignmentVariable::Constant(value) => {
+                    encoder.encode_tag(0)?;
+                    encoder.encode_field(value)?;
+                }
+                AssignmentVariable::Public(index) => {
+                    encoder.encode_tag(1)?;
+                    encoder.encode_u64(*index)?;
+                }
+                AssignmentVariable::Private(index) => {
+                    encoder.encode_tag(2)?;
+                    encoder.encode_u64(*index)?;
+                }
+            }
+            encoder.encode_field(coefficient)?;
+        }
+        Ok(())
+    }
+}
+
+impl<F: Field> Assignment<F> {
+    /// Encodes the structure of the assignment, which ignores the variable values and the circuit version, in order:
+    /// 1. the number of public variables, private variables, and constraints,
+    /// 2. the `A`, `B`, and `C` linear combinations of each constraint,
+    /// 3. the number of lookup tables, and for each table, its number of entries, followed by
+    ///    the `(key[0], key[1], value)` of each entry, sorted by key,
+    /// 4. the number of lookup constraints, and for each lookup constraint, its `A`, `B`, and `C`
+    ///    linear combinations, followed by its table index.
+    pub(super) fn encode_structure(&self, encoder: &mut impl StructureEncoder<F>) -> Result<()> {
+        encoder.encode_u64(self.num_public())?;
+        encoder.encode_u64(self.num_private())?;
+
+        encoder.encode_u64(self.constraints.len() as u64)?;
+        for (a, b, c) in &self.constraints {
+            a.encode_structure(encoder)?;
+            b.encode_structure(encoder)?;
+            c.encode_structure(encoder)?;
+        }
+
+        encoder.encode_u64(self.tables.len() as u64)?;
+        for table in &self.tables {
+            let mut entries = table.table.iter().collect::<Vec<_>>();
+            entries.sort_unstable();
+            encoder.encode_u64(entries.len() as u64)?;
+            for ([a, b], c) in entries {
+                encoder.encode_field(a)?;
+                encoder.encode_field(b)?;
+                encoder.encode_field(c)?;
+            }
+        }
+
+        encoder.encode_u64(self.lookup_constraints.len() as u64)?;
+        for (a, b, c, table_index) in &self.lookup_constraints {
+            a.encode_structure(encoder)?;
+            b.encode_structure(encoder)?;
+            c.encode_structure(encoder)?;
+            encoder.encode_u64(*table_index as u64)?;
+        }
+        Ok(())
+    }
+
+    /// Returns a hash of the structure of the assignment, which ignores the variable values.
+    /// Key-compatible assignments (see [`Assignment::is_key_compatible`]) share the same structure hash,
+    /// so the hash may be used to look up the proving key of a previously indexed circuit.
+    /// The circuit version is not hashed, as the keys depend only on the structure.
+    ///
+    /// This is the identity of a circuit that keys the [`ProvingKeyCache`]. It is the BLAKE2s hash of the same
+    /// encoding of the structure that [`Assignment::hash`] absorbs into an algebraic sponge.
+    pub fn structure_hash(&self) -> Result<[u8; 32]> {
+        let mut encoder = WriterEncoder(blake2::Blake2s256::new());
+        self.encode_structure(&mut encoder)?;
+        Ok(encoder.0.finalize().into())
+    }
+
+    /// Ensures `self` and `other` have the same number of public variables, private variables, constraints,
+    /// lookup tables, and lookup constraints, in that order, or returns an error describing the first that differs.
+    pub fn verify_shape_matches(&self, other: &Self) -> Result<(), snarkvm_algorithms::r1cs::SynthesisError> {
+        let dimensions = [
+            ("public variables", self.num_public(), other.num_public()),
+            ("private variables", self.num_private(), other.num_private()),
+            ("constraints", self.num_constraints(), other.num_constraints()),
+            ("lookup tables", self.num_lookup_tables(), other.num_lookup_tables()),
+            ("lookup constraints", self.num_lookup_constraints(), other.num_lookup_constraints()),
+        ];
+        match dimensions.into_iter().find(|(_, expected, found)| expected != found) {
+            Some((dimension, expected, found)) => Err(snarkvm_algorithms::r1cs::SynthesisError::AssignmentMismatch(
+                format!("Expected {expected} {dimension}, found {found}"),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns `true` if `self` and `other` may share the same proving and verifying keys.
+    /// This requires both assignments to have the same number of variables, the same constraints,
+    /// and the same lookup tables and lookup constraints. The variable values are ignored.
+    /// If both assignments are tagged with a circuit version, the versions must also match.
+    pub fn is_key_compatible(&self, other: &Self) -> bool {
+        // Ensure the circuit versions match, if both are present.
+        if let (Some(version), Some(other_version)) = (self.circuit_version, other.circuit_version) {
+            if version != other_version {
+                return false;
+            }
+        }
+
+        self.num_public() == other.num_public()
+            && self.num_private() == other.num_private()
+            && self.constraints == other.constraints
+            && self.has_same_tables(other)
+            && self.lookup_constraints == other.lookup_constraints
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_curves::bls12_377::Fr;
+
+    #[test]
+    fn test_is_key_compatible() {
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+
+        // Ensure an assignment with different values is compatible.
+        let mut other = assignment.clone();
+        other.private.values_mut().for_each(|value| *value += *value);
+        assert!(assignment.is_key_compatible(&other));
+
+        // Ensure an untagged assignment is compatible with a tagged assignment.
+        assert!(assignment.is_key_compatible(&other.clone().with_circuit_version(1)));
+
+        // Ensure assignments with the same version are compatible.
+        let assignment = assignment.with_circuit_version(1);
+        assert!(assignment.is_key_compatible(&other.clone().with_circuit_version(1)));
+
+        // Ensure assignments with mismatched versions are incompatible, even though the structures coincide.
+        assert!(!assignment.is_key_compatible(&other.clone().with_circuit_version(2)));
+
+        // Ensure assignments with different structures are incompatible.
+        let mut other = assignment.clone();
+        other.constraints.pop();
+        assert!(!assignment.is_key_compatible(&other));
+    }
+
+    #[test]
+    fn test_verify_shape_matches() {
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+        assert!(assignment.verify_shape_matches(&assignment.clone()).is_ok());
+
+        // Ensure the first dimension that differs is reported.
+        let mut other = assignment.clone();
+        other.constraints.pop();
+        other.private.pop();
+        let error = assignment.verify_shape_matches(&other).unwrap_err();
+        assert_eq!("Assignment mismatch: Expected 3 private variables, found 2", error.to_string());
+
+        let mut other = assignment.clone();
+        other.tables.push(Default::default());
+        let error = assignment.verify_shape_matches(&other).unwrap_err();
+        assert_eq!("Assignment mismatch: Expected 0 lookup tables, found 1", error.to_string());
+    }
+
+    #[test]
+    fn test_structure_hash() {
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+        let hash = assignment.structure_hash().unwrap();
+
+        // Ensure an assignment with different values has the same hash.
+        let mut other = assignment.clone();
+        other.private.values_mut().for_each(|value| *value += *value);
+        assert_eq!(hash, other.structure_hash().unwrap());
+
+        // Ensure the circuit version does not affect the hash.
+        assert_eq!(hash, other.clone().with_circuit_version(1).structure_hash().unwrap());
+
+        // Ensure the order of the terms does not affect the hash.
+        assert_eq!(hash, other.clone().canonical_form().structure_hash().unwrap());
+
+        // Ensure an assignment with a different structure has a different hash.
+        other.constraints.pop();
+        assert_ne!(hash, other.structure_hash().unwrap());
+    }
+}
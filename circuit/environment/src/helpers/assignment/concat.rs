@@ -0,0 +1,339 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_algorithms::r1cs::SynthesisError;
+
+/// Returns the given index offset by the given amount, or an `Overflow` error if the result does not fit in an index.
+fn checked_offset(mode: &str, index: Index, offset: Index) -> Result<Index, SynthesisError> {
+    index
+        .checked_add(offset)
+        .ok_or_else(|| SynthesisError::Overflow(format!("{mode} index {index} overflows when offset by {offset}")))
+}
+
+impl<F: Field> AssignmentLC<F> {
+    /// Returns the linear combination, with the indices of its public and private variables mapped by the given functions.
+    fn map_indices(&self, public: impl Fn(Index) -> Index, private: impl Fn(Index) -> Index) -> Self {
+        let terms = self
+            .terms
+            .iter()
+            .map(|(variable, coefficient)| {
+                let variable = match variable {
+                    AssignmentVariable::Constant(value) => AssignmentVariable::Constant(*value),
+                    AssignmentVariable::Public(index) => AssignmentVariable::Public(public(*index)),
+                    AssignmentVariable::Private(index) => AssignmentVariable::Private(private(*index)),
+                };
+                (variable, *coefficient)
+            })
+            .collect();
+        Self { constant: self.constant, terms }
+    }
+
+    /// Returns the linear combination, with the indices of its public and private variables offset by the given amounts.
+    /// Returns an `Overflow` error if an offset index does not fit in an index.
+    fn offset_variables(&self, public_offset: Index, private_offset: Index) -> Result<Self, SynthesisError> {
+        // Ensure every index can be offset, before mapping the indices.
+        for variable in self.terms.keys() {
+            match variable {
+                AssignmentVariable::Constant(_) => continue,
+                AssignmentVariable::Public(index) => checked_offset("Public", *index, public_offset)?,
+                AssignmentVariable::Private(index) => checked_offset("Private", *index, private_offset)?,
+            };
+        }
+        Ok(self.map_indices(|index| index + public_offset, |index| index + private_offset))
+    }
+}
+
+impl<F: Field> Assignment<F> {
+    /// Returns the assignment, with every public and private index offset by the given amounts,
+    /// both in the variables and in the terms of the constraints and lookup constraints.
+    ///
+    /// Constant variables, lookup tables, and the table indices of lookup constraints are unchanged,
+    /// so the result references exactly the variables it holds. As the indices no longer start from zero
+    /// (unless both offsets are zero), the result is meant for composition (see [`Assignment::concat_independent`]),
+    /// rather than for synthesis on its own (see [`Assignment::validate`]).
+    ///
+    /// The result is not tagged with a circuit version, as it is a different circuit from the assignment.
+    ///
+    /// Returns an `Overflow` error if an offset index, of a variable or of a term, does not fit in an index.
+    pub fn offset_indices(&self, public_offset: Index, private_offset: Index) -> Result<Self, SynthesisError> {
+        let offset = |lc: &AssignmentLC<F>| lc.offset_variables(public_offset, private_offset);
+        Ok(Self {
+            public: self
+                .public
+                .iter()
+                .map(|(index, value)| Ok((checked_offset("Public", *index, public_offset)?, *value)))
+                .collect::<Result<_, SynthesisError>>()?,
+            private: self
+                .private
+                .iter()
+                .map(|(index, value)| Ok((checked_offset("Private", *index, private_offset)?, *value)))
+                .collect::<Result<_, SynthesisError>>()?,
+            constraints: self
+                .constraints
+                .iter()
+                .map(|(a, b, c)| Ok((offset(a)?, offset(b)?, offset(c)?)))
+                .collect::<Result<_, SynthesisError>>()?,
+            tables: self.tables.clone(),
+            lookup_constraints: self
+                .lookup_constraints
+                .iter()
+                .map(|(a, b, c, table_index)| Ok((offset(a)?, offset(b)?, offset(c)?, *table_index)))
+                .collect::<Result<_, SynthesisError>>()?,
+            circuit_version: None,
+        })
+    }
+
+    /// Returns an assignment of the two independent circuits side by side, which is satisfied iff both are.
+    ///
+    /// The constant wire is shared: the first public variable of `other` (its constant `1`) is mapped to the first
+    /// public variable of `self`, so the result has a single constant `1`. The remaining variables, constraints,
+    /// lookup tables, and lookup constraints of `other` follow those of `self`, with the remaining public indices
+    /// of `other` offset by `self.num_public() - 1`, its private indices offset by `self.num_private()`, and the
+    /// table indices of its lookup constraints offset by the number of lookup tables in `self`.
+    /// Identical lookup tables are not merged (see [`Assignment::dedup_tables`]).
+    ///
+    /// The result is not tagged with a circuit version, as it is a different circuit from either assignment.
+    ///
+    /// Returns an `AssignmentMismatch` error if either assignment is not valid (see [`Assignment::validate`]),
+    /// or does not start with the constant `1` as its first public variable, and an `Overflow` error if an
+    /// offset index does not fit.
+    pub fn concat_independent(&self, other: &Self) -> Result<Self, SynthesisError> {
+        // Ensure both assignments are valid, and start with the constant `1` that is shared.
+        for (name, assignment) in [("first", self), ("second", other)] {
+            assignment.validate()?;
+            if assignment.public.get(&0) != Some(&F::one()) {
+                return Err(SynthesisError::AssignmentMismatch(format!(
+                    "The {name} assignment must start with the constant 1 as its first public variable"
+                )));
+            }
+        }
+
+        // Offset the variables of `other`, which moves its constant `1` to public index `public_offset`.
+        let (public_offset, private_offset) = (self.num_public() - 1, self.num_private());
+        let table_offset = self.tables.len();
+        let offset = other.offset_indices(public_offset, private_offset)?;
+
+        // Map the constant `1` of `other` back to the first public variable, which is shared with `self`.
+        let share = |index: Index| if index == public_offset { 0 } else { index };
+        let map = |lc: &AssignmentLC<F>| lc.map_indices(share, |index| index);
+
+        let public = self
+            .public
+            .iter()
+            .map(|(index, value)| (*index, *value))
+            .chain(offset.public.into_iter().filter(|(index, _)| *index != public_offset))
+            .collect();
+        let private = self.private.iter().map(|(index, value)| (*index, *value)).chain(offset.private).collect();
+        let constraints = self
+            .constraints
+            .iter()
+            .cloned()
+            .chain(offset.constraints.iter().map(|(a, b, c)| (map(a), map(b), map(c))))
+            .collect();
+        let tables = self.tables.iter().chain(&other.tables).cloned().collect();
+        let lookup_constraints = self
+            .lookup_constraints
+            .iter()
+            .cloned()
+            .map(Ok)
+            .chain(offset.lookup_constraints.iter().map(|(a, b, c, table_index)| {
+                let table_index = table_index.checked_add(table_offset).ok_or_else(|| {
+                    SynthesisError::Overflow(format!(
+                        "Table index {table_index} overflows when offset by {table_offset}"
+                    ))
+                })?;
+                Ok((map(a), map(b), map(c), table_index))
+            }))
+            .collect::<Result<_, SynthesisError>>()?;
+
+        Ok(Self { public, private, constraints, tables, lookup_constraints, circuit_version: None })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::r1cs::{ConstraintSynthesizer, TestConstraintChecker};
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+
+    /// Returns an assignment computing `x * x^2` with a lookup table, where `x = 2`.
+    fn create_lookup_assignment() -> Assignment<Fr> {
+        let mut assignment = Assignment::<Fr>::new();
+        let x = assignment.allocate_private(Fr::from(2u64));
+        let x2 = assignment.allocate_private(Fr::from(4u64));
+        let x3 = assignment.allocate_private(Fr::from(8u64));
+        let variable = |index| AssignmentLC {
+            constant: Fr::zero(),
+            terms: [(AssignmentVariable::Private(index), Fr::one())].into(),
+        };
+        assignment.append_constraint(variable(x), variable(x), variable(x2));
+
+        let mut table = LookupTable::default();
+        table.fill([Fr::from(2u64), Fr::from(4u64)], Fr::from(8u64));
+        assignment.tables.push(table);
+        assignment.lookup_constraints.push((variable(x), variable(x2), variable(x3), 0));
+        assignment
+    }
+
+    #[test]
+    fn test_offset_indices() {
+        let assignment = create_lookup_assignment().with_circuit_version(1);
+        assert_eq!(assignment, assignment.offset_indices(0, 0).unwrap().with_circuit_version(1));
+
+        let offset = assignment.offset_indices(2, 3).unwrap();
+        assert_eq!(None, offset.circuit_version());
+        assert_eq!(vec![2], offset.public.keys().copied().collect::<Vec<_>>());
+        assert_eq!(vec![3, 4, 5], offset.private.keys().copied().collect::<Vec<_>>());
+        assert_eq!(assignment.public.values().collect::<Vec<_>>(), offset.public.values().collect::<Vec<_>>());
+        assert_eq!(assignment.private.values().collect::<Vec<_>>(), offset.private.values().collect::<Vec<_>>());
+        assert_eq!(assignment.tables[0].table, offset.tables[0].table);
+
+        // Ensure the terms are offset consistently with the variables.
+        assert_eq!((None, Some(5)), offset.max_variable_index());
+        assert!(offset.lookup_constraints[0].2.contains(&AssignmentVariable::Private(5)));
+        assert!(offset.is_satisfied());
+
+        // Ensure the constants are unchanged.
+        let mut other = assignment.clone();
+        other.constraints[0].0.terms.insert(AssignmentVariable::Public(0), Fr::one());
+        other.constraints[0].0.constant = Fr::from(7u64);
+        let offset = other.offset_indices(2, 3).unwrap();
+        assert_eq!(Fr::from(7u64), offset.constraints[0].0.constant);
+        assert!(offset.constraints[0].0.contains(&AssignmentVariable::Public(2)));
+    }
+
+    #[test]
+    fn test_offset_indices_overflow() {
+        // The largest private index is 2, so it can be offset by at most `u64::MAX - 2`.
+        let assignment = create_lookup_assignment();
+        let offset = assignment.offset_indices(u64::MAX, u64::MAX - 2).unwrap();
+        assert_eq!(vec![u64::MAX], offset.public.keys().copied().collect::<Vec<_>>());
+        assert_eq!((None, Some(u64::MAX)), offset.max_variable_index());
+        assert!(matches!(assignment.offset_indices(0, u64::MAX - 1), Err(SynthesisError::Overflow(_))));
+        assert!(matches!(assignment.offset_indices(1, u64::MAX), Err(SynthesisError::Overflow(_))));
+
+        // Ensure an index referenced only by the terms is checked as well.
+        let mut other = assignment;
+        other.constraints[0].0.terms.insert(AssignmentVariable::Public(3), Fr::one());
+        assert!(other.offset_indices(u64::MAX - 3, 0).is_ok());
+        let error = other.offset_indices(u64::MAX - 2, 0).unwrap_err();
+        assert_eq!(
+            format!("Integer overflow: Public index 3 overflows when offset by {}", u64::MAX - 2),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_concat_independent() {
+        let first = super::super::tests::create_example_assignment::<Fr>().with_circuit_version(1);
+        let second = create_lookup_assignment();
+        assert!(first.is_satisfied() && second.is_satisfied());
+
+        let combined = first.concat_independent(&second).unwrap();
+        assert_eq!(first.num_public() + second.num_public() - 1, combined.num_public());
+        assert_eq!(first.num_private() + second.num_private(), combined.num_private());
+        assert_eq!(first.num_constraints() + second.num_constraints(), combined.num_constraints());
+        assert_eq!(1, combined.num_lookup_tables());
+        assert_eq!(1, combined.num_lookup_constraints());
+        assert_eq!(None, combined.circuit_version());
+        assert!(combined.validate().is_ok());
+        assert!(combined.is_satisfied());
+
+        // Ensure the constant `1` is shared, so the combined instance has a single constant `1`.
+        let mut second_public = second.public_input_values();
+        second_public.remove(0);
+        assert_eq!([first.public_input_values(), second_public].concat(), combined.public_input_values());
+        let mut other = second.clone();
+        let output = other.allocate_public(Fr::from(8u64));
+        let (a, _, _) = other.constraints[0].clone();
+        let lc = |variable| AssignmentLC { constant: Fr::zero(), terms: [(variable, Fr::one())].into() };
+        other.append_constraint(a.clone(), lc(AssignmentVariable::Public(0)), a);
+        other.append_constraint(
+            lc(AssignmentVariable::Public(output)),
+            lc(AssignmentVariable::Public(0)),
+            lc(AssignmentVariable::Private(2)),
+        );
+        let combined_other = first.concat_independent(&other).unwrap();
+        assert_eq!(first.num_public() + other.num_public() - 1, combined_other.num_public());
+        let (_, b, _) = &combined_other.constraints[first.constraints.len() + 1];
+        assert!(b.contains(&AssignmentVariable::Public(0)));
+        let (a, _, _) = &combined_other.constraints[first.constraints.len() + 2];
+        assert!(a.contains(&AssignmentVariable::Public(first.num_public())));
+        assert!(combined_other.is_satisfied());
+
+        // Ensure the combined assignment synthesizes with its lookups, and is recorded back as is.
+        let mut cs = TestConstraintChecker::new();
+        combined.generate_constraints(&mut cs).unwrap();
+        assert!(cs.is_satisfied());
+        assert_eq!(combined.num_constraints() + combined.num_lookup_constraints(), cs.num_constraints() as u64);
+        let mut cs = RecordingConstraintSystem::new();
+        combined.generate_constraints(&mut cs).unwrap();
+        assert_eq!(&combined, cs.assignment());
+
+        // Ensure the combined assignment is not satisfied if either assignment is not satisfied.
+        let mut other = first.clone();
+        other.private[1] += Fr::one();
+        assert!(!other.concat_independent(&second).unwrap().is_satisfied());
+        assert!(!second.concat_independent(&other).unwrap().is_satisfied());
+
+        let mut other = second.clone();
+        other.private[2] += Fr::one();
+        assert!(!first.concat_independent(&other).unwrap().is_satisfied());
+        assert!(!other.concat_independent(&first).unwrap().is_satisfied());
+
+        // Ensure the table indices of the second assignment are offset.
+        let combined = second.concat_independent(&second).unwrap();
+        assert_eq!(vec![0, 1], combined.lookup_constraints().iter().map(|(_, _, _, index)| *index).collect::<Vec<_>>());
+        assert!(combined.is_satisfied());
+    }
+
+    #[test]
+    fn test_concat_independent_invalid() {
+        let first = super::super::tests::create_example_assignment::<Fr>();
+        let second = create_lookup_assignment();
+
+        // Ensure non-contiguous indices are rejected, rather than colliding in the combined assignment.
+        let mut other = second.clone();
+        let value = other.private.shift_remove(&1).unwrap();
+        other.private.insert(3, value);
+        assert!(matches!(first.concat_independent(&other), Err(SynthesisError::AssignmentMismatch(_))));
+        assert!(matches!(other.concat_independent(&first), Err(SynthesisError::AssignmentMismatch(_))));
+
+        // Ensure a term referencing a missing variable is rejected.
+        let mut other = second.clone();
+        other.constraints[0].0.terms.insert(AssignmentVariable::Public(5), Fr::one());
+        assert!(matches!(first.concat_independent(&other), Err(SynthesisError::AssignmentMismatch(_))));
+
+        // Ensure an assignment without the constant `1` is rejected, on either side.
+        let mut other = second.clone();
+        other.public.clear();
+        other.constraints.clear();
+        other.lookup_constraints.clear();
+        let error = first.concat_independent(&other).unwrap_err();
+        assert_eq!(
+            "Assignment mismatch: The second assignment must start with the constant 1 as its first public variable",
+            error.to_string()
+        );
+        let error = other.concat_independent(&first).unwrap_err();
+        assert_eq!(
+            "Assignment mismatch: The first assignment must start with the constant 1 as its first public variable",
+            error.to_string()
+        );
+        other.public.insert(0, Fr::from(2u64));
+        assert!(first.concat_independent(&other).is_err());
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A mapping of the variables from the virtual machine (first) to the gadget constraint system (second).
+///
+/// The mapping depends only on the shape of an assignment, and not on its values, so it is prepared once
+/// from the base of a [`SameCircuitAssignment`] and reused when synthesizing each of its witnesses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreparedConverter {
+    pub(super) public: IndexMap<Index, snarkvm_algorithms::r1cs::Variable>,
+    pub(super) private: IndexMap<Index, snarkvm_algorithms::r1cs::Variable>,
+}
+
+impl PreparedConverter {
+    /// Prepares the converter for the given assignment.
+    ///
+    /// The variables are mapped in the order in which they are allocated during synthesis, so the `i`-th
+    /// public variable maps to public variable `i + 1` of the second system (as the second system allocates
+    /// its own constant `1` first), and the `i`-th private variable maps to private variable `i`.
+    /// The index ordering of the variables is not checked here, but when each witness is synthesized.
+    pub fn new<F: Field>(assignment: &Assignment<F>) -> Self {
+        let public = assignment
+            .public
+            .keys()
+            .enumerate()
+            .map(|(i, index)| {
+                (
+                    *index,
+                    snarkvm_algorithms::r1cs::Variable::new_unchecked(snarkvm_algorithms::r1cs::Index::Public(i + 1)),
+                )
+            })
+            .collect();
+        let private = assignment
+            .private
+            .keys()
+            .enumerate()
+            .map(|(i, index)| {
+                (*index, snarkvm_algorithms::r1cs::Variable::new_unchecked(snarkvm_algorithms::r1cs::Index::Private(i)))
+            })
+            .collect();
+        Self { public, private }
+    }
+
+    /// Returns the number of public variables in the converter.
+    pub fn num_public(&self) -> u64 {
+        self.public.len() as u64
+    }
+
+    /// Returns the number of private variables in the converter.
+    pub fn num_private(&self) -> u64 {
+        self.private.len() as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::r1cs::{ConstraintSynthesizer, ConstraintSystem, TestConstraintSystem};
+    use snarkvm_curves::bls12_377::Fr;
+
+    #[test]
+    fn test_prepared_converter() {
+        let base = super::super::tests::create_example_assignment::<Fr>();
+        let converter = PreparedConverter::new(&base);
+        assert_eq!(base.num_public(), converter.num_public());
+        assert_eq!(base.num_private(), converter.num_private());
+
+        // Ensure the converter matches the variables allocated during synthesis.
+        let mut cs = TestConstraintSystem::new();
+        base.generate_constraints(&mut cs).unwrap();
+        assert_eq!(cs.num_public_variables(), converter.public.len() + 1);
+        assert_eq!(cs.num_private_variables(), converter.private.len());
+        for (i, (index, variable)) in converter.public.iter().enumerate() {
+            assert_eq!(i as u64, *index);
+            assert_eq!(snarkvm_algorithms::r1cs::Index::Public(i + 1), variable.get_unchecked());
+        }
+        for (i, (index, variable)) in converter.private.iter().enumerate() {
+            assert_eq!(i as u64, *index);
+            assert_eq!(snarkvm_algorithms::r1cs::Index::Private(i), variable.get_unchecked());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_prepared_converter_is_shared() {
+        let base = super::super::tests::create_example_assignment::<Fr>();
+        let mut cs = TestConstraintSystem::new();
+        base.generate_constraints(&mut cs).unwrap();
+
+        // Ensure the converter is shared by witnesses of the same base.
+        let witnesses = vec![base.clone(), base.clone()];
+        let assignments = SameCircuitAssignment::create_with_bases(Arc::new(base), witnesses).unwrap();
+        assert!(Arc::ptr_eq(&assignments[0].converter, &assignments[1].converter));
+
+        // Ensure a witness added later reuses the converter, and synthesizes as before.
+        let assignment = assignments[0].with_witness((*assignments[0].base).clone()).unwrap();
+        assert!(Arc::ptr_eq(&assignments[0].converter, &assignment.converter));
+        let mut other_cs = TestConstraintSystem::new();
+        assignment.generate_constraints(&mut other_cs).unwrap();
+        assert_eq!(cs.num_constraints(), other_cs.num_constraints());
+        assert!(other_cs.is_satisfied());
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_algorithms::r1cs::SynthesisError;
+
+/// The structural and witness differences between two assignments, as returned by [`Assignment::diff`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AssignmentDiff {
+    /// The indices of the constraints in the other assignment, beyond the constraints of this assignment.
+    pub added_constraints: Vec<usize>,
+    /// The indices of the constraints in this assignment, beyond the constraints of the other assignment.
+    pub removed_constraints: Vec<usize>,
+    /// The indices of the constraints in both assignments that differ.
+    pub changed_constraints: Vec<usize>,
+    /// The indices of the public variables whose values differ, or that are in only one of the assignments.
+    pub changed_public: Vec<Index>,
+    /// The indices of the private variables whose values differ, or that are in only one of the assignments.
+    pub changed_private: Vec<Index>,
+}
+
+impl AssignmentDiff {
+    /// Returns `true` if the assignments have the same constraints and values.
+    pub fn is_empty(&self) -> bool {
+        self.added_constraints.is_empty()
+            && self.removed_constraints.is_empty()
+            && self.changed_constraints.is_empty()
+            && self.changed_public.is_empty()
+            && self.changed_private.is_empty()
+    }
+}
+
+impl<F: Field> Assignment<F> {
+    /// Returns the differences between the constraints and variables of `self` and `other`.
+    ///
+    /// The constraints are compared by position, as in [`Assignment::changed_constraints`], and the constraints
+    /// beyond the shorter of the two assignments are reported as added or removed. The variables are compared by
+    /// index, in increasing order. The lookup tables and lookup constraints are not compared.
+    pub fn diff(&self, other: &Self) -> AssignmentDiff {
+        let num_common = self.constraints.len().min(other.constraints.len());
+        let changed_constraints = self.constraints[..num_common]
+            .iter()
+            .zip(&other.constraints[..num_common])
+            .enumerate()
+            .filter(|(_, (constraint, other_constraint))| constraint != other_constraint)
+            .map(|(i, _)| i)
+            .collect();
+
+        // Returns the indices of the variables whose values differ, or that are in only one of the maps.
+        let changed_variables = |variables: &IndexMap<Index, F>, other_variables: &IndexMap<Index, F>| {
+            let mut indices = variables
+                .iter()
+                .filter(|(index, value)| other_variables.get(*index) != Some(*value))
+                .map(|(index, _)| *index)
+                .chain(other_variables.keys().filter(|index| !variables.contains_key(*index)).copied())
+                .collect::<Vec<_>>();
+            indices.sort_unstable();
+            indices
+        };
+
+        AssignmentDiff {
+            added_constraints: (num_common..other.constraints.len()).collect(),
+            removed_constraints: (num_common..self.constraints.len()).collect(),
+            changed_constraints,
+            changed_public: changed_variables(&self.public, &other.public),
+            changed_private: changed_variables(&self.private, &other.private),
+        }
+    }
+
+    /// Returns the indices of the constraints that differ between `self` and `other`.
+    /// Two constraints are the same if their `A`, `B`, and `C` linear combinations have the same
+    /// constant and terms, irrespective of the order of the terms.
+    /// Returns an error if the assignments have a different number of constraints.
+    pub fn changed_constraints(&self, other: &Self) -> Result<Vec<usize>, SynthesisError> {
+        // Ensure the number of constraints match.
+        if self.constraints.len() != other.constraints.len() {
+            return Err(SynthesisError::AssignmentMismatch(format!(
+                "Mismatching number of constraints: {} != {}",
+                self.constraints.len(),
+                other.constraints.len()
+            )));
+        }
+
+        Ok(self
+            .constraints
+            .iter()
+            .zip(&other.constraints)
+            .enumerate()
+            .filter(|(_, (constraint, other_constraint))| constraint != other_constraint)
+            .map(|(i, _)| i)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::One;
+
+    #[test]
+    fn test_changed_constraints() {
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+
+        // Ensure reordering the terms of a constraint is not a change.
+        let mut other = assignment.clone();
+        other.constraints[2].0.terms.reverse();
+        assert!(assignment.changed_constraints(&other).unwrap().is_empty());
+
+        // Ensure modifying one constraint reports only that constraint.
+        other.constraints[1].2.constant += Fr::one();
+        assert_eq!(vec![1], assignment.changed_constraints(&other).unwrap());
+
+        // Ensure a mismatched number of constraints is an error.
+        other.constraints.pop();
+        assert!(assignment.changed_constraints(&other).is_err());
+    }
+
+    #[test]
+    fn test_diff() {
+        use super::AssignmentDiff;
+
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+        assert!(assignment.diff(&assignment).is_empty());
+
+        // Ensure reordering the terms of a constraint is not a change.
+        let mut other = assignment.clone();
+        other.constraints[2].0.terms.reverse();
+        assert!(assignment.diff(&other).is_empty());
+
+        // Ensure the changed constraints and values are reported.
+        other.constraints[1].2.constant += Fr::one();
+        other.public[1] += Fr::one();
+        other.private[0] += Fr::one();
+        other.private.insert(3, Fr::one());
+        let expected = AssignmentDiff {
+            changed_constraints: vec![1],
+            changed_public: vec![1],
+            changed_private: vec![0, 3],
+            ..Default::default()
+        };
+        assert_eq!(expected, assignment.diff(&other));
+
+        // Ensure the added and removed constraints are reported.
+        let mut other = assignment.clone();
+        other.constraints.pop();
+        assert_eq!(AssignmentDiff { removed_constraints: vec![2], ..Default::default() }, assignment.diff(&other));
+        assert_eq!(AssignmentDiff { added_constraints: vec![2], ..Default::default() }, other.diff(&assignment));
+    }
+}
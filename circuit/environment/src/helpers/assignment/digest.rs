@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use super::compatible::StructureEncoder;
+use snarkvm_algorithms::AlgebraicSponge;
+
+/// The number of field elements to buffer before absorbing them into the sponge.
+const ABSORB_CHUNK_SIZE: usize = 1 << 12;
+
+/// Encodes the structure of an assignment as field elements, absorbing them into the sponge in chunks.
+struct SpongeEncoder<F: PrimeField, S: AlgebraicSponge<F, RATE>, const RATE: usize> {
+    sponge: S,
+    elements: Vec<F>,
+}
+
+impl<F: PrimeField, S: AlgebraicSponge<F, RATE>, const RATE: usize> SpongeEncoder<F, S, RATE> {
+    /// Absorbs the buffered field elements into the sponge.
+    fn flush(&mut self) {
+        self.sponge.absorb_native_field_elements(&self.elements);
+        self.elements.clear();
+    }
+}
+
+impl<F: PrimeField, S: AlgebraicSponge<F, RATE>, const RATE: usize> StructureEncoder<F> for SpongeEncoder<F, S, RATE> {
+    fn encode_u64(&mut self, value: u64) -> Result<()> {
+        self.encode_field(&F::from(value))
+    }
+
+    fn encode_tag(&mut self, tag: u8) -> Result<()> {
+        self.encode_u64(tag as u64)
+    }
+
+    fn encode_field(&mut self, value: &F) -> Result<()> {
+        self.elements.push(*value);
+        if self.elements.len() == ABSORB_CHUNK_SIZE {
+            self.flush();
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> Assignment<F> {
+    /// Returns a digest of the structure of the assignment, squeezed from the given algebraic sponge.
+    /// The public and private values are not absorbed, so all witnesses of a circuit share the same digest.
+    ///
+    /// The sponge absorbs the encoding of the structure that [`Assignment::structure_hash`] hashes, which is
+    /// the identity of a circuit that keys the [`ProvingKeyCache`], with every count, tag, and variable index
+    /// absorbed as a field element. As such, two assignments have the same digest iff they have the same
+    /// structure hash, barring collisions, and this digest is meant for use where an algebraic hash is cheaper.
+    pub fn hash<S: AlgebraicSponge<F, RATE>, const RATE: usize>(&self) -> F {
+        let mut encoder = SpongeEncoder { sponge: S::new(), elements: Vec::with_capacity(ABSORB_CHUNK_SIZE) };
+        // Encoding into the sponge is infallible.
+        self.encode_structure(&mut encoder).expect("Failed to absorb the structure of the assignment");
+        encoder.flush();
+        encoder.sponge.squeeze_native_field_elements(1)[0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::crypto_hash::PoseidonSponge;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+
+    type Sponge = PoseidonSponge<Fr, 2, 1>;
+
+    #[test]
+    fn test_hash() {
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+        let hash = assignment.hash::<Sponge, 2>();
+        assert_eq!(hash, assignment.hash::<Sponge, 2>());
+
+        // Ensure the hash ignores the public and private values.
+        let mut other = assignment.clone();
+        other.public[1] += Fr::one();
+        other.private.values_mut().for_each(|value| *value += *value);
+        assert_eq!(hash, other.hash::<Sponge, 2>());
+
+        // Ensure the hash changes with a coefficient.
+        let mut other = assignment.clone();
+        other.constraints[0].0.constant += Fr::one();
+        assert_ne!(hash, other.hash::<Sponge, 2>());
+
+        // Ensure the hash changes with a variable index.
+        let mut other = assignment.clone();
+        other.private.insert(3, Fr::zero());
+        assert_ne!(hash, other.hash::<Sponge, 2>());
+
+        // Ensure the hash changes with the lookup tables, but not with the order of their entries.
+        let mut other = assignment.clone();
+        let mut table = super::super::tests::create_example_table::<Fr>();
+        table.fill([Fr::from(2u64), Fr::from(4u64)], Fr::from(8u64));
+        other.tables.push(table);
+        let with_table = other.hash::<Sponge, 2>();
+        assert_ne!(hash, with_table);
+
+        let mut table = LookupTable::default();
+        table.fill([Fr::from(2u64), Fr::from(4u64)], Fr::from(8u64));
+        table.fill([Fr::from(3u64), Fr::from(9u64)], Fr::from(27u64));
+        other.tables[0] = table;
+        assert_eq!(with_table, other.hash::<Sponge, 2>());
+
+        // Ensure the digest and the structure hash agree on whether the structure changed.
+        let mut other = assignment.clone();
+        other.constraints[2].0.terms.reverse();
+        other.private[0] += Fr::one();
+        assert_eq!(assignment.structure_hash().unwrap(), other.structure_hash().unwrap());
+        assert_eq!(hash, other.hash::<Sponge, 2>());
+        let (a, b, c) = other.constraints[0].clone();
+        other.lookup_constraints.push((a, b, c, 0));
+        assert_ne!(assignment.structure_hash().unwrap(), other.structure_hash().unwrap());
+        assert_ne!(hash, other.hash::<Sponge, 2>());
+    }
+}
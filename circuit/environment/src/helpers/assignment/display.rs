@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::fmt;
+
+impl<F: Field> fmt::Display for AssignmentVariable<F> {
+    /// Prints the variable as `const(value)`, `x_pub[index]`, or `x_prv[index]`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Constant(value) => write!(f, "const({value})"),
+            Self::Public(index) => write!(f, "x_pub[{index}]"),
+            Self::Private(index) => write!(f, "x_prv[{index}]"),
+        }
+    }
+}
+
+impl<F: Field> fmt::Display for AssignmentLC<F> {
+    /// Prints the linear combination as a sum of its nonzero terms, in order, followed by its constant,
+    /// such as `3*x_prv[2] + 5*x_pub[0] + 7`. The constant is omitted if it is zero, unless there are no terms.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut is_empty = true;
+        for (variable, coefficient) in self.terms.iter().filter(|(_, coefficient)| !coefficient.is_zero()) {
+            match is_empty {
+                true => write!(f, "{coefficient}*{variable}")?,
+                false => write!(f, " + {coefficient}*{variable}")?,
+            }
+            is_empty = false;
+        }
+        match (is_empty, self.constant.is_zero()) {
+            (true, _) => write!(f, "{}", self.constant),
+            (false, false) => write!(f, " + {}", self.constant),
+            (false, true) => Ok(()),
+        }
+    }
+}
+
+impl<F: Field> Assignment<F> {
+    /// Returns a deterministic textual dump of the structure of the assignment, for golden-file regression tests.
+    ///
+    /// The dump starts with the number of public and private variables, followed by one line per constraint
+    /// as `A: <lc> ; B: <lc> ; C: <lc>`, one line per lookup constraint (with its table index), and one line
+    /// per lookup table (with its number of entries). The terms of each linear combination are sorted by
+    /// variable, so the dump does not depend on the order in which the terms were added.
+    /// The values of the variables are not included.
+    pub fn to_dimacs_like_debug(&self) -> String {
+        // Returns the linear combination, with its terms sorted by variable.
+        let sorted = |lc: &AssignmentLC<F>| {
+            let mut lc = lc.clone();
+            lc.terms.sort_keys();
+            lc
+        };
+
+        let mut lines = vec![format!("public: {}, private: {}", self.num_public(), self.num_private())];
+        lines.extend(
+            self.constraints.iter().enumerate().map(|(i, (a, b, c))| {
+                format!("constraint {i}: A: {} ; B: {} ; C: {}", sorted(a), sorted(b), sorted(c))
+            }),
+        );
+        lines.extend(self.lookup_constraints.iter().enumerate().map(|(i, (a, b, c, table_index))| {
+            format!(
+                "lookup constraint {i} (table {table_index}): A: {} ; B: {} ; C: {}",
+                sorted(a),
+                sorted(b),
+                sorted(c)
+            )
+        }));
+        lines.extend(
+            self.tables.iter().enumerate().map(|(i, table)| format!("table {i}: {} entries", table.table.len())),
+        );
+        lines.into_iter().map(|line| line + "\n").collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+
+    #[test]
+    fn test_display() {
+        assert_eq!("const(7)", AssignmentVariable::Constant(Fr::from(7u64)).to_string());
+        assert_eq!("x_pub[0]", AssignmentVariable::<Fr>::Public(0).to_string());
+        assert_eq!("x_prv[2]", AssignmentVariable::<Fr>::Private(2).to_string());
+
+        let mut lc = AssignmentLC {
+            constant: Fr::from(7u64),
+            terms: [
+                (AssignmentVariable::Private(2), Fr::from(3u64)),
+                (AssignmentVariable::Public(1), Fr::zero()),
+                (AssignmentVariable::Public(0), Fr::from(5u64)),
+            ]
+            .into_iter()
+            .collect(),
+        };
+        assert_eq!("3*x_prv[2] + 5*x_pub[0] + 7", lc.to_string());
+
+        // Ensure a zero constant is omitted.
+        lc.constant = Fr::zero();
+        assert_eq!("3*x_prv[2] + 5*x_pub[0]", lc.to_string());
+
+        // Ensure a linear combination without nonzero terms prints its constant.
+        let lc = AssignmentLC { constant: Fr::zero(), terms: [(AssignmentVariable::Private(0), Fr::zero())].into() };
+        assert_eq!("0", lc.to_string());
+        let lc = AssignmentLC::<Fr> { constant: Fr::one(), terms: Default::default() };
+        assert_eq!("1", lc.to_string());
+    }
+
+    #[test]
+    fn test_to_dimacs_like_debug() {
+        let mut assignment = super::super::tests::create_example_assignment::<Fr>();
+        let expected = "\
+public: 2, private: 3
+constraint 0: A: 1*x_prv[0] ; B: 1*x_prv[0] ; C: 1*x_prv[1]
+constraint 1: A: 1*x_prv[1] ; B: 1*x_prv[0] ; C: 1*x_prv[2]
+constraint 2: A: 1*x_prv[0] + 1*x_prv[2] + 5 ; B: 1 ; C: 1*x_pub[1]
+";
+        assert_eq!(expected, assignment.to_dimacs_like_debug());
+
+        // Ensure the dump does not depend on the order of the terms, nor on the values.
+        assignment.constraints[2].0.terms.reverse();
+        assignment.private[0] += Fr::one();
+        assert_eq!(expected, assignment.to_dimacs_like_debug());
+
+        // Ensure the lookup constraints and tables are included.
+        let (a, b, c) = assignment.constraints[0].clone();
+        let mut table = snarkvm_algorithms::r1cs::LookupTable::default();
+        table.fill([Fr::from(3u64), Fr::from(3u64)], Fr::from(9u64));
+        assignment.tables.push(table);
+        assignment.lookup_constraints.push((a, b, c, 0));
+        assert_eq!(
+            format!(
+                "{expected}lookup constraint 0 (table 0): A: 1*x_prv[0] ; B: 1*x_prv[0] ; C: 1*x_prv[1]\ntable 0: 1 entries\n"
+            ),
+            assignment.to_dimacs_like_debug()
+        );
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<F: Field> AssignmentLC<F> {
+    /// Returns the linear combination, with the indices of its public and private variables
+    /// replaced by their positions in the given sets.
+    fn reindex_variables(&self, public: &IndexSet<Index>, private: &IndexSet<Index>) -> Self {
+        let position = |indices: &IndexSet<Index>, index| {
+            indices.get_index_of(index).expect("The referenced variable must be retained") as Index
+        };
+        let terms = self
+            .terms
+            .iter()
+            .map(|(variable, coefficient)| {
+                let variable = match variable {
+                    AssignmentVariable::Constant(value) => AssignmentVariable::Constant(*value),
+                    AssignmentVariable::Public(index) => AssignmentVariable::Public(position(public, index)),
+                    AssignmentVariable::Private(index) => AssignmentVariable::Private(position(private, index)),
+                };
+                (variable, *coefficient)
+            })
+            .collect();
+        Self { constant: self.constant, terms }
+    }
+}
+
+impl<F: Field> Assignment<F> {
+    /// Returns the sub-circuit of the constraints for which `predicate(i, constraint)` is `true`,
+    /// where `i` is the position of the constraint in the assignment.
+    ///
+    /// The sub-circuit only retains the variables referenced by the retained constraints, along with
+    /// the first public variable (the constant `1`). The retained variables keep their relative order
+    /// and are re-indexed to be contiguous from zero, so the result is valid if the assignment is valid
+    /// (see [`Assignment::validate`]). The lookup tables and lookup constraints are not retained.
+    ///
+    /// The result is not tagged with a circuit version, as it is a different circuit from the assignment.
+    pub fn filter_constraints<P>(&self, predicate: P) -> Self
+    where
+        P: Fn(usize, &(AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>)) -> bool,
+    {
+        let constraints = self
+            .constraints
+            .iter()
+            .enumerate()
+            .filter(|(i, constraint)| predicate(*i, constraint))
+            .map(|(_, constraint)| constraint)
+            .collect::<Vec<_>>();
+
+        // Determine the retained variables, in index order.
+        let (mut public, mut private) = (IndexSet::new(), IndexSet::new());
+        public.extend(self.public.keys().next().copied());
+        for variable in constraints.iter().flat_map(|(a, b, c)| [a, b, c]).flat_map(|lc| lc.terms.keys()) {
+            match variable {
+                AssignmentVariable::Constant(_) => continue,
+                AssignmentVariable::Public(index) => public.insert(*index),
+                AssignmentVariable::Private(index) => private.insert(*index),
+            };
+        }
+        public.sort_unstable();
+        private.sort_unstable();
+
+        let values = |variables: &IndexMap<Index, F>, indices: &IndexSet<Index>| {
+            indices
+                .iter()
+                .enumerate()
+                .filter_map(|(i, index)| variables.get(index).map(|value| (i as Index, *value)))
+                .collect()
+        };
+        let reindex = |lc: &AssignmentLC<F>| lc.reindex_variables(&public, &private);
+
+        Self {
+            public: values(&self.public, &public),
+            private: values(&self.private, &private),
+            constraints: constraints.into_iter().map(|(a, b, c)| (reindex(a), reindex(b), reindex(c))).collect(),
+            tables: vec![],
+            lookup_constraints: vec![],
+            circuit_version: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::r1cs::{ConstraintSynthesizer, TestConstraintSystem};
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::One;
+
+    #[test]
+    fn test_filter_constraints() {
+        let assignment = super::super::tests::create_example_assignment::<Fr>().with_circuit_version(1);
+
+        // Ensure retaining every constraint preserves the assignment.
+        let all = assignment.filter_constraints(|_, _| true);
+        assert_eq!(assignment.constraints, all.constraints);
+        assert_eq!(assignment.public, all.public);
+        assert_eq!(assignment.private, all.private);
+        assert_eq!(None, all.circuit_version());
+
+        // Ensure retaining no constraints only keeps the constant `1`.
+        let none = assignment.filter_constraints(|_, _| false);
+        assert_eq!((1, 0, 0), (none.num_public(), none.num_private(), none.num_constraints()));
+
+        // Ensure the retained variables are re-indexed to be contiguous.
+        let (x2, x3) = (AssignmentVariable::Private(1), AssignmentVariable::Private(2));
+        let second = assignment.filter_constraints(|_, (a, _, c)| a.contains(&x2) && c.contains(&x3));
+        assert_eq!(1, second.num_constraints());
+        assert_eq!(1, second.num_public());
+        assert_eq!(
+            vec![Fr::from(3u64), Fr::from(9u64), Fr::from(27u64)],
+            second.private.values().copied().collect::<Vec<_>>()
+        );
+        let last = assignment.filter_constraints(|i, _| i == 2);
+        assert_eq!((2, 2, 1), (last.num_public(), last.num_private(), last.num_constraints()));
+        assert_eq!(vec![Fr::from(3u64), Fr::from(27u64)], last.private.values().copied().collect::<Vec<_>>());
+        assert!(last.constraints[0].0.contains(&AssignmentVariable::Private(1)));
+        assert!(last.validate().is_ok());
+        assert!(last.is_satisfied());
+
+        // Ensure the sub-circuit synthesizes.
+        let mut cs = TestConstraintSystem::new();
+        last.generate_constraints(&mut cs).unwrap();
+        assert!(cs.is_satisfied());
+
+        // Ensure an unsatisfied constraint remains unsatisfied in the sub-circuit.
+        let mut other = assignment.clone();
+        other.public[1] += Fr::one();
+        assert!(!other.filter_constraints(|i, _| i == 2).is_satisfied());
+        assert!(other.filter_constraints(|i, _| i < 2).is_satisfied());
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use std::path::Path;
+
+impl<F: Field> Assignment<F> {
+    /// Writes the canonical form of the assignment to the given path, for use as a golden file in tests.
+    ///
+    /// Equivalent assignments share the same canonical form, so the fixture is stable across runs and machines.
+    /// Fixtures should only be regenerated intentionally, as a changed fixture indicates a changed circuit.
+    pub fn write_fixture(&self, path: &Path) -> Result<()> {
+        let bytes = self.clone().canonical_form().to_bytes_le()?;
+        Ok(std::fs::write(path, bytes)?)
+    }
+
+    /// Reads an assignment from a fixture written with [`Assignment::write_fixture`].
+    /// Returns an error if the fixture has trailing bytes after the assignment.
+    pub fn read_fixture(path: &Path) -> Result<Self> {
+        let bytes = std::fs::read(path)?;
+        Self::from_bytes_le(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+
+    #[test]
+    fn test_fixture() -> Result<()> {
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+        let path = std::env::temp_dir().join(format!("assignment_fixture_{}.bin", std::process::id()));
+
+        assignment.write_fixture(&path)?;
+        let candidate = Assignment::<Fr>::read_fixture(&path)?;
+
+        // Ensure a fixture with an appended byte is rejected.
+        let mut bytes = std::fs::read(&path)?;
+        bytes.push(0);
+        std::fs::write(&path, bytes)?;
+        let error = Assignment::<Fr>::read_fixture(&path).unwrap_err();
+        std::fs::remove_file(&path)?;
+        assert_eq!("Found 1 trailing bytes after the assignment", error.to_string());
+
+        // Ensure the fixture holds the canonical form of the assignment.
+        let expected = assignment.canonical_form();
+        assert_eq!(expected, candidate);
+        assert_eq!(expected.to_bytes_le()?, candidate.to_bytes_le()?);
+        Ok(())
+    }
+}
@@ -0,0 +1,388 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_algorithms::r1cs::SynthesisError;
+
+impl<F: Field> Assignment<F> {
+    /// Ensures the table index of every lookup constraint refers to a lookup table of the assignment.
+    pub fn validate_lookup_indices(&self) -> Result<(), SynthesisError> {
+        match self.lookup_constraints.iter().position(|(_, _, _, table_index)| *table_index >= self.tables.len()) {
+            Some(i) => Err(SynthesisError::AssignmentMismatch(format!(
+                "Lookup constraint {i} references table {}, but there are only {} tables",
+                self.lookup_constraints[i].3,
+                self.tables.len()
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Ensures every lookup constraint `(A, B) -> C` is satisfied by the witness, i.e. that `(A, B)` evaluates
+    /// to a row of its lookup table, and `C` evaluates to the output of that row.
+    /// Returns an error for the first lookup constraint that references a missing table or variable,
+    /// or whose evaluated row is absent from its table. The constraints themselves are not checked
+    /// (see [`Assignment::find_unsatisfied_constraint`]).
+    pub fn validate_lookups(&self) -> Result<(), SynthesisError> {
+        self.validate_lookup_indices()?;
+        for (i, (a, b, c, table_index)) in self.lookup_constraints.iter().enumerate() {
+            let evaluate = |lc| {
+                self.evaluate_lc(lc).ok_or_else(|| {
+                    SynthesisError::AssignmentMismatch(format!(
+                        "Lookup constraint {i} references a variable that has not been allocated"
+                    ))
+                })
+            };
+            let (a, b, c) = (evaluate(a)?, evaluate(b)?, evaluate(c)?);
+            match self.tables[*table_index].lookup(&[a, b]) {
+                Some((_, _, value)) if *value == c => continue,
+                Some((_, _, value)) => {
+                    return Err(SynthesisError::AssignmentMismatch(format!(
+                        "Lookup constraint {i} evaluates to ({a}, {b}) -> {c}, but table {table_index} maps ({a}, {b}) -> {value}"
+                    )));
+                }
+                None => {
+                    return Err(SynthesisError::AssignmentMismatch(format!(
+                        "Lookup constraint {i} evaluates to ({a}, {b}) -> {c}, which is not a row of table {table_index}"
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures the lookup constraints on the given table behave as a function under the current witness.
+    /// That is, whenever two lookup constraints on the table share the same inputs `(A, B)`,
+    /// they must also share the same output `C`.
+    pub fn validate_lookup_functional(&self, table_index: usize) -> Result<(), SynthesisError> {
+        // Ensure the table exists.
+        if table_index >= self.tables.len() {
+            return Err(SynthesisError::AssignmentMismatch(format!("Lookup table {table_index} does not exist")));
+        }
+
+        // Maps each input to the output, and the index of the lookup constraint that first produced it.
+        let mut outputs = IndexMap::<[F; 2], (F, usize)>::new();
+        for (i, (a, b, c, _)) in
+            self.lookup_constraints.iter().enumerate().filter(|(_, (_, _, _, index))| *index == table_index)
+        {
+            let evaluate = |lc| {
+                self.evaluate_lc(lc).ok_or_else(|| {
+                    SynthesisError::AssignmentMismatch(format!(
+                        "Lookup constraint {i} references a variable that has not been allocated"
+                    ))
+                })
+            };
+            let (input, output) = ([evaluate(a)?, evaluate(b)?], evaluate(c)?);
+
+            match outputs.get(&input) {
+                Some((expected, j)) if *expected != output => {
+                    return Err(SynthesisError::AssignmentMismatch(format!(
+                        "Lookup constraints {j} and {i} on table {table_index} share inputs, but not outputs"
+                    )));
+                }
+                Some(_) => continue,
+                None => {
+                    outputs.insert(input, (output, i));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns each table index, along with the number of lookup constraints that reference the table.
+    /// A table with a count of zero is never looked up, and may be removed (see [`Assignment::prune_unused_tables`]).
+    /// Lookup constraints that reference a table that does not exist are not counted.
+    pub fn lookup_table_usage(&self) -> Vec<(usize, u64)> {
+        let mut usage = (0..self.tables.len()).map(|table_index| (table_index, 0u64)).collect::<Vec<_>>();
+        for (_, _, _, table_index) in &self.lookup_constraints {
+            if let Some((_, count)) = usage.get_mut(*table_index) {
+                *count += 1;
+            }
+        }
+        usage
+    }
+
+    /// Removes the lookup tables that are not referenced by any lookup constraint, and rewrites the table index
+    /// of each lookup constraint to the new position of its table. Returns the number of tables removed.
+    pub fn prune_unused_tables(&mut self) -> u64 {
+        // Maps each table index to its new index, or `None` if the table is removed.
+        let mut remapping = Vec::with_capacity(self.tables.len());
+        let mut tables = Vec::with_capacity(self.tables.len());
+        for ((_, count), table) in self.lookup_table_usage().into_iter().zip(core::mem::take(&mut self.tables)) {
+            match count {
+                0 => remapping.push(None),
+                _ => {
+                    remapping.push(Some(tables.len()));
+                    tables.push(table);
+                }
+            }
+        }
+        self.tables = tables;
+
+        // Rewrite the table indices, leaving any out-of-range index as is.
+        for (_, _, _, table_index) in self.lookup_constraints.iter_mut() {
+            if let Some(Some(index)) = remapping.get(*table_index) {
+                *table_index = *index;
+            }
+        }
+        remapping.iter().filter(|index| index.is_none()).count() as u64
+    }
+
+    /// Sorts the entries of every lookup table by their inputs, so that the order of the entries does not depend
+    /// on the order in which they were inserted. The tables themselves are not reordered, so the table index
+    /// of every lookup constraint remains valid.
+    pub fn canonicalize_tables(&mut self) {
+        for table in self.tables.iter_mut() {
+            table.table.sort_keys();
+        }
+    }
+
+    /// Removes the lookup tables that are identical to an earlier table, including in the order of their entries,
+    /// and rewrites the table index of each lookup constraint to the first occurrence of its table.
+    pub fn dedup_tables(&mut self) {
+        // Maps each table index to the index of its first occurrence, among the remaining tables.
+        let mut remapping = Vec::with_capacity(self.tables.len());
+        let mut tables: Vec<LookupTable<F>> = Vec::with_capacity(self.tables.len());
+        for table in core::mem::take(&mut self.tables) {
+            match tables.iter().position(|other| table.table.iter().eq(other.table.iter())) {
+                Some(index) => remapping.push(index),
+                None => {
+                    remapping.push(tables.len());
+                    tables.push(table);
+                }
+            }
+        }
+        self.tables = tables;
+
+        // Rewrite the table indices, leaving any out-of-range index as is.
+        for (_, _, _, table_index) in self.lookup_constraints.iter_mut() {
+            if let Some(index) = remapping.get(*table_index) {
+                *table_index = *index;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::tests::{create_example_assignment, create_example_assignment_with_lookup, create_example_table, lc},
+        *,
+    };
+    use snarkvm_curves::bls12_377::Fr;
+
+    #[test]
+    fn test_validate_lookup_indices() {
+        use snarkvm_algorithms::r1cs::{ConstraintSynthesizer, TestConstraintSystem};
+
+        assert!(create_example_assignment::<Fr>().validate_lookup_indices().is_ok());
+        let mut assignment = create_example_assignment_with_lookup::<Fr>();
+        assert!(assignment.validate_lookup_indices().is_ok());
+
+        // Ensure an out-of-range table index is rejected, before reaching the constraint system.
+        let (a, b, c, _) = assignment.lookup_constraints[0].clone();
+        assignment.lookup_constraints.push((a, b, c, 1));
+        let error = assignment.validate_lookup_indices().unwrap_err();
+        assert_eq!(
+            "Assignment mismatch: Lookup constraint 1 references table 1, but there are only 1 tables",
+            error.to_string()
+        );
+
+        let mut cs = TestConstraintSystem::new();
+        let result = assignment.generate_constraints(&mut cs);
+        assert!(matches!(result, Err(SynthesisError::AssignmentMismatch(_))));
+        assert_eq!(0, cs.num_constraints());
+    }
+
+    #[test]
+    fn test_validate_lookup_functional() {
+        assert!(create_example_assignment::<Fr>().validate_lookup_functional(0).is_err());
+
+        // Look up the example table twice with the same inputs.
+        let mut assignment = create_example_assignment_with_lookup::<Fr>();
+        assignment.lookup_constraints.push(assignment.lookup_constraints[0].clone());
+        assert!(assignment.validate_lookup_functional(0).is_ok());
+
+        // Ensure an inconsistent witness is rejected.
+        let (a, b, _, _) = assignment.lookup_constraints[0].clone();
+        assignment.lookup_constraints.push((a, b, lc(AssignmentVariable::Public(1)), 0));
+        let error = assignment.validate_lookup_functional(0).unwrap_err();
+        assert_eq!(
+            "Assignment mismatch: Lookup constraints 0 and 2 on table 0 share inputs, but not outputs",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_validate_lookups() {
+        assert!(create_example_assignment::<Fr>().validate_lookups().is_ok());
+        let mut assignment = create_example_assignment_with_lookup::<Fr>();
+        assert!(assignment.validate_lookups().is_ok());
+        let (x, x2, x3) = (
+            lc(AssignmentVariable::Private(0)),
+            lc(AssignmentVariable::Private(1)),
+            lc(AssignmentVariable::Private(2)),
+        );
+
+        // Ensure a row that is absent from the table is rejected, even though the constraints are satisfied.
+        let mut other = assignment.clone();
+        other.lookup_constraints.push((x2.clone(), x.clone(), x3.clone(), 0));
+        assert_eq!(Some(UnsatisfiedConstraint::LookupConstraint(1)), other.find_unsatisfied_constraint());
+        assert_eq!(
+            "Assignment mismatch: Lookup constraint 1 evaluates to (9, 3) -> 27, which is not a row of table 0",
+            other.validate_lookups().unwrap_err().to_string()
+        );
+
+        // Ensure a mismatched output is rejected.
+        let mut other = assignment.clone();
+        other.lookup_constraints.push((x.clone(), x2.clone(), x2.clone(), 0));
+        assert_eq!(
+            "Assignment mismatch: Lookup constraint 1 evaluates to (3, 9) -> 9, but table 0 maps (3, 9) -> 27",
+            other.validate_lookups().unwrap_err().to_string()
+        );
+
+        // Ensure an unallocated variable and a missing table are rejected.
+        let mut other = assignment.clone();
+        other.lookup_constraints.push((x.clone(), x2.clone(), lc(AssignmentVariable::Private(3)), 0));
+        assert!(other.validate_lookups().is_err());
+        assignment.lookup_constraints.push((x, x2, x3, 1));
+        assert!(assignment.validate_lookups().is_err());
+    }
+
+    #[test]
+    fn test_lookup_table_usage() {
+        let mut assignment = create_example_assignment::<Fr>();
+        assert!(assignment.lookup_table_usage().is_empty());
+
+        // Declare three tables, of which the second is never looked up.
+        assignment.tables = vec![create_example_table(), create_example_table(), create_example_table()];
+        for table_index in [2, 0, 2] {
+            assignment.lookup_constraints.push((
+                lc(AssignmentVariable::Private(0)),
+                lc(AssignmentVariable::Private(1)),
+                lc(AssignmentVariable::Private(2)),
+                table_index,
+            ));
+        }
+        assert!(assignment.is_satisfied());
+        assert_eq!(vec![(0, 1), (1, 0), (2, 2)], assignment.lookup_table_usage());
+
+        // Ensure a reference to a missing table is not counted.
+        assignment.lookup_constraints[0].3 = 3;
+        assert_eq!(vec![(0, 1), (1, 0), (2, 1)], assignment.lookup_table_usage());
+    }
+
+    #[test]
+    fn test_prune_unused_tables() {
+        use snarkvm_algorithms::r1cs::{ConstraintSynthesizer, TestConstraintChecker};
+
+        let mut assignment = create_example_assignment::<Fr>();
+        assert_eq!(0, assignment.prune_unused_tables());
+
+        // Declare an unused table, followed by the example table.
+        let mut unused_table = LookupTable::default();
+        unused_table.fill([Fr::from(1u64), Fr::from(2u64)], Fr::from(3u64));
+        let table = create_example_table();
+        assignment.tables = vec![unused_table.clone(), table.clone(), unused_table];
+        assignment.lookup_constraints.push((
+            lc(AssignmentVariable::Private(0)),
+            lc(AssignmentVariable::Private(1)),
+            lc(AssignmentVariable::Private(2)),
+            1,
+        ));
+        assert!(assignment.is_satisfied());
+
+        // Ensure the unused tables are removed, and the used table is remapped.
+        assert_eq!(2, assignment.prune_unused_tables());
+        assert_eq!(1, assignment.num_lookup_tables());
+        assert_eq!(table.table, assignment.tables[0].table);
+        assert_eq!(0, assignment.lookup_constraints[0].3);
+        assert_eq!(vec![(0, 1)], assignment.lookup_table_usage());
+        assert!(assignment.validate().is_ok());
+        assert!(assignment.is_satisfied());
+
+        // Ensure the assignment still synthesizes.
+        let mut cs = TestConstraintChecker::new();
+        assignment.generate_constraints(&mut cs).unwrap();
+        assert!(cs.is_satisfied());
+        assert_eq!(0, assignment.prune_unused_tables());
+    }
+
+    #[test]
+    fn test_canonicalize_tables() {
+        use snarkvm_utilities::ToBytes;
+
+        let mut first = create_example_assignment::<Fr>();
+        first.lookup_constraints.push((
+            lc(AssignmentVariable::Private(0)),
+            lc(AssignmentVariable::Private(1)),
+            lc(AssignmentVariable::Private(2)),
+            1,
+        ));
+
+        // Add the same entries to the tables, in a different order for each assignment.
+        let entries = (1..5u64).map(|x| ([Fr::from(x), Fr::from(x * x)], Fr::from(x * x * x))).collect::<Vec<_>>();
+        let mut second = first.clone();
+        for (assignment, entries) in [(&mut first, entries.clone()), (&mut second, entries.into_iter().rev().collect())]
+        {
+            let mut table = LookupTable::default();
+            for (inputs, output) in entries {
+                table.fill(inputs, output);
+            }
+            assignment.tables = vec![LookupTable::default(), table];
+        }
+        assert!(first.is_satisfied() && second.is_satisfied());
+        assert_ne!(first.to_bytes_le().unwrap(), second.to_bytes_le().unwrap());
+
+        // Ensure the assignments canonicalize identically, and remain satisfied.
+        first.canonicalize_tables();
+        second.canonicalize_tables();
+        assert_eq!(first.to_bytes_le().unwrap(), second.to_bytes_le().unwrap());
+        assert!(first.tables[1].table.keys().zip(first.tables[1].table.keys().skip(1)).all(|(a, b)| a < b));
+        assert!(first.is_satisfied() && second.is_satisfied());
+        assert!(first.validate_lookup_indices().is_ok());
+    }
+
+    #[test]
+    fn test_dedup_tables() {
+        let mut assignment = create_example_assignment::<Fr>();
+
+        // Add the example table twice, with a different table in between.
+        let (x, x2, x3) = (
+            lc(AssignmentVariable::Private(0)),
+            lc(AssignmentVariable::Private(1)),
+            lc(AssignmentVariable::Private(2)),
+        );
+        let table = create_example_table();
+        let mut other_table = LookupTable::default();
+        other_table.fill([Fr::from(9u64), Fr::from(3u64)], Fr::from(27u64));
+        assignment.tables = vec![table.clone(), other_table.clone(), table];
+        for table_index in [0, 1, 2] {
+            let (a, b) = match table_index {
+                1 => (x2.clone(), x.clone()),
+                _ => (x.clone(), x2.clone()),
+            };
+            assignment.lookup_constraints.push((a, b, x3.clone(), table_index));
+        }
+        assert!(assignment.is_satisfied());
+
+        // Ensure the duplicate table is removed, and the indices are remapped.
+        assignment.dedup_tables();
+        assert_eq!(2, assignment.num_lookup_tables());
+        assert_eq!(other_table.table, assignment.tables[1].table);
+        let table_indices = assignment.lookup_constraints.iter().map(|(_, _, _, index)| *index).collect::<Vec<_>>();
+        assert_eq!(vec![0, 1, 0], table_indices);
+        assert!(assignment.is_satisfied());
+    }
+}
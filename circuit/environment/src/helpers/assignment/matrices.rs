@@ -0,0 +1,372 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_algorithms::r1cs::SynthesisError;
+use snarkvm_utilities::io::Write;
+
+/// The constraint matrices of an assignment, where each constraint enforces `(A * B) == C`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Matrix {
+    A,
+    B,
+    C,
+}
+
+impl<F: Field> Assignment<F> {
+    /// Returns the wire of the given variable in the synthesized constraint system.
+    /// The constant wire is `0`, followed by the public variables, and then the private variables.
+    pub(super) fn wire(&self, variable: &AssignmentVariable<F>) -> usize {
+        match variable {
+            AssignmentVariable::Constant(_) => 0,
+            AssignmentVariable::Public(index) => *index as usize + 1,
+            AssignmentVariable::Private(index) => self.public.len() + 1 + *index as usize,
+        }
+    }
+
+    /// Returns the nonzero `(wire, coefficient)` entries of the given linear combination, in order of the terms,
+    /// with the constant on the constant wire first.
+    pub(crate) fn wire_entries(&self, lc: &AssignmentLC<F>) -> Vec<(usize, F)> {
+        // Accumulate the constant, including any constant terms.
+        let constant = lc.terms.iter().fold(lc.constant, |constant, (variable, coefficient)| match variable {
+            AssignmentVariable::Constant(value) => constant + *value * coefficient,
+            _ => constant,
+        });
+
+        let mut entries = Vec::with_capacity(lc.terms.len() + 1);
+        if !constant.is_zero() {
+            entries.push((0, constant));
+        }
+        for (variable, coefficient) in lc.terms.iter() {
+            if !matches!(variable, AssignmentVariable::Constant(_)) && !coefficient.is_zero() {
+                entries.push((self.wire(variable), *coefficient));
+            }
+        }
+        entries
+    }
+
+    /// Returns the linear combinations of the given matrix, with one row per constraint,
+    /// followed by one row per lookup constraint.
+    fn matrix_rows(&self, matrix: Matrix) -> impl Iterator<Item = &AssignmentLC<F>> {
+        let select = move |a, b, c| match matrix {
+            Matrix::A => a,
+            Matrix::B => b,
+            Matrix::C => c,
+        };
+        self.constraints
+            .iter()
+            .map(move |(a, b, c)| select(a, b, c))
+            .chain(self.lookup_constraints.iter().map(move |(a, b, c, _)| select(a, b, c)))
+    }
+}
+
+impl<F: Field> Assignment<F> {
+    /// Initializes an assignment from the given dense constraint matrices and variables.
+    /// Each row is the linear combination of one constraint, where column `0` is the constant,
+    /// followed by one column per public variable, and then one column per private variable.
+    /// Zero entries are omitted from the resulting linear combinations.
+    pub fn from_dense_matrices(
+        a: Vec<Vec<F>>,
+        b: Vec<Vec<F>>,
+        c: Vec<Vec<F>>,
+        public: Vec<F>,
+        private: Vec<F>,
+    ) -> Result<Self, SynthesisError> {
+        // Ensure the matrices have the same number of rows.
+        if a.len() != b.len() || b.len() != c.len() {
+            return Err(SynthesisError::AssignmentMismatch(format!(
+                "Mismatching number of rows: {}, {}, {}",
+                a.len(),
+                b.len(),
+                c.len()
+            )));
+        }
+        // Ensure every row has one column per wire.
+        let num_columns = 1 + public.len() + private.len();
+        for (name, matrix) in [("A", &a), ("B", &b), ("C", &c)] {
+            if let Some(i) = matrix.iter().position(|row| row.len() != num_columns) {
+                return Err(SynthesisError::AssignmentMismatch(format!(
+                    "Row {i} of {name} has {} columns, but expected {num_columns}",
+                    matrix[i].len()
+                )));
+            }
+        }
+
+        // Converts a dense row into a linear combination.
+        let num_public = public.len();
+        let to_lc = |row: Vec<F>| {
+            let mut row = row.into_iter();
+            let constant = row.next().unwrap_or_default();
+            let terms = row
+                .enumerate()
+                .filter(|(_, coefficient)| !coefficient.is_zero())
+                .map(|(column, coefficient)| match column < num_public {
+                    true => (AssignmentVariable::Public(column as Index), coefficient),
+                    false => (AssignmentVariable::Private((column - num_public) as Index), coefficient),
+                })
+                .collect();
+            AssignmentLC { constant, terms }
+        };
+
+        Ok(Self {
+            public: public.into_iter().enumerate().map(|(i, value)| (i as Index, value)).collect(),
+            private: private.into_iter().enumerate().map(|(i, value)| (i as Index, value)).collect(),
+            constraints: a.into_iter().zip(b).zip(c).map(|((a, b), c)| (to_lc(a), to_lc(b), to_lc(c))).collect(),
+            tables: Vec::new(),
+            lookup_constraints: Vec::new(),
+            circuit_version: None,
+        })
+    }
+
+    /// Returns the given matrix of the assignment in dense form, with one row per constraint.
+    /// The columns are the wires, starting with the constant wire, then the public variables,
+    /// and then the private variables. Lookup constraints are not included, as they have no dense form.
+    ///
+    /// Returns an error if the assignment is not valid (see [`Assignment::validate`]), such as when a linear
+    /// combination references a variable that is not in the assignment, and would otherwise alias another column.
+    pub fn to_dense(&self, which: Matrix) -> Result<Vec<Vec<F>>, SynthesisError> {
+        // Ensure every referenced variable has its own column.
+        self.validate()?;
+
+        let num_columns = 1 + self.public.len() + self.private.len();
+        Ok(self
+            .constraints
+            .iter()
+            .map(|(a, b, c)| match which {
+                Matrix::A => a,
+                Matrix::B => b,
+                Matrix::C => c,
+            })
+            .map(|lc| {
+                let mut row = vec![F::zero(); num_columns];
+                for (column, coefficient) in self.wire_entries(lc) {
+                    row[column] += coefficient;
+                }
+                row
+            })
+            .collect())
+    }
+
+    /// Returns the `A`, `B`, and `C` matrices of the assignment as sparse `(row, column, value)` triplets.
+    /// The rows are the constraints, followed by the lookup constraints, and the columns are the wires,
+    /// starting with the constant wire, then the public variables, and then the private variables.
+    ///
+    /// Returns an error if the assignment is not valid (see [`Assignment::validate`]), such as when a linear
+    /// combination references a variable that is not in the assignment, and would otherwise alias another column.
+    #[allow(clippy::type_complexity)]
+    pub fn to_matrices(
+        &self,
+    ) -> Result<(Vec<(usize, usize, F)>, Vec<(usize, usize, F)>, Vec<(usize, usize, F)>), SynthesisError> {
+        // Ensure every referenced variable has its own column.
+        self.validate()?;
+
+        let triplets = |which| {
+            self.matrix_rows(which)
+                .enumerate()
+                .flat_map(|(row, lc)| {
+                    self.wire_entries(lc).into_iter().map(move |(column, value)| (row, column, value))
+                })
+                .collect()
+        };
+        Ok((triplets(Matrix::A), triplets(Matrix::B), triplets(Matrix::C)))
+    }
+
+    /// Returns the sparsity pattern of the `A`, `B`, and `C` matrices of the assignment, as one flag per wire,
+    /// which is `true` iff the column of the wire has a nonzero entry in some row of the matrix.
+    ///
+    /// The rows are the constraints, followed by the lookup constraints, as in [`Assignment::to_matrices`].
+    /// The wires are numbered as in the synthesized constraint system: wire `0` is the constant `1`,
+    /// public variable `i` is wire `i + 1`, and private variable `i` is wire `num_public + 1 + i`.
+    /// As such, each pattern has `1 + num_public + num_private` flags.
+    ///
+    /// Returns an error if the assignment is not valid (see [`Assignment::validate`]), such as when a linear
+    /// combination references a variable that is not in the assignment, and thus has no column.
+    #[allow(clippy::type_complexity)]
+    pub fn sparsity_pattern(&self) -> Result<(Vec<bool>, Vec<bool>, Vec<bool>), SynthesisError> {
+        // Ensure every referenced variable has a column.
+        self.validate()?;
+
+        let num_columns = 1 + self.public.len() + self.private.len();
+        let pattern = |which| {
+            let mut pattern = vec![false; num_columns];
+            for (column, _) in self.matrix_rows(which).flat_map(|lc| self.wire_entries(lc)) {
+                pattern[column] = true;
+            }
+            pattern
+        };
+        Ok((pattern(Matrix::A), pattern(Matrix::B), pattern(Matrix::C)))
+    }
+}
+
+impl<F: PrimeField> Assignment<F> {
+    /// Writes the given matrix of the assignment in the Matrix Market coordinate format.
+    /// The rows are the constraints, followed by the lookup constraints, and the columns are the wires,
+    /// starting with the constant wire, then the public variables, and then the private variables.
+    /// As the format is 1-indexed, the entry for constraint `i` and wire `j` is written at `(i + 1, j + 1)`.
+    ///
+    /// Returns an error if the assignment is not valid (see [`Assignment::validate`]), and writes nothing.
+    pub fn to_matrix_market<W: Write>(&self, which: Matrix, mut writer: W) -> Result<()> {
+        // Ensure every referenced variable has its own column.
+        self.validate()?;
+
+        // Collect the entries of the matrix.
+        let rows = self.matrix_rows(which).map(|lc| self.wire_entries(lc)).collect::<Vec<_>>();
+        let num_rows = rows.len();
+        let num_columns = 1 + self.public.len() + self.private.len();
+        let num_entries = rows.iter().map(|row| row.len()).sum::<usize>();
+
+        // Write the header.
+        writeln!(writer, "%%MatrixMarket matrix coordinate integer general")?;
+        writeln!(writer, "{num_rows} {num_columns} {num_entries}")?;
+        // Write the entries.
+        for (row, entries) in rows.iter().enumerate() {
+            for (column, value) in entries {
+                writeln!(writer, "{} {} {value}", row + 1, column + 1)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+
+    #[test]
+    fn test_to_matrix_market() {
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+
+        let mut output = Vec::new();
+        assignment.to_matrix_market(Matrix::A, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let lines = output.lines().collect::<Vec<_>>();
+
+        // There are 3 constraints, over the constant wire, 2 public variables, and 3 private variables.
+        assert_eq!("%%MatrixMarket matrix coordinate integer general", lines[0]);
+        assert_eq!("3 6 5", lines[1]);
+        // `A` of the first constraint is `x`, which is the first private variable.
+        assert_eq!("1 4 1", lines[2]);
+        // `A` of the second constraint is `x^2`.
+        assert_eq!("2 5 1", lines[3]);
+        // `A` of the third constraint is `5 + x^3 + x`.
+        assert_eq!(["3 1 5", "3 6 1", "3 4 1"], lines[4..]);
+
+        let mut output = Vec::new();
+        assignment.to_matrix_market(Matrix::B, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        // `B` of the third constraint is the constant `1`.
+        assert_eq!(Some("3 1 1"), output.lines().last());
+
+        // Ensure an invalid assignment is rejected before anything is written.
+        for variable in [AssignmentVariable::Public(2), AssignmentVariable::Constant(Fr::one())] {
+            let mut invalid = assignment.clone();
+            invalid.constraints[0].0.terms.insert(variable, Fr::one());
+            let mut output = Vec::new();
+            assert!(invalid.to_matrix_market(Matrix::A, &mut output).is_err());
+            assert!(output.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_to_matrices() {
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+        let (a, b, c) = assignment.to_matrices().unwrap();
+
+        // Ensure there is one triplet per nonzero.
+        let (num_nonzeros_a, num_nonzeros_b, num_nonzeros_c) = assignment.num_nonzeros();
+        assert_eq!(num_nonzeros_a, a.len() as u64);
+        assert_eq!(num_nonzeros_b, b.len() as u64);
+        assert_eq!(num_nonzeros_c, c.len() as u64);
+
+        // Ensure the triplets match the dense matrices.
+        for (triplets, which) in [(a, Matrix::A), (b, Matrix::B), (c, Matrix::C)] {
+            let mut dense = vec![vec![Fr::zero(); 6]; 3];
+            for (row, column, value) in triplets {
+                dense[row][column] += value;
+            }
+            assert_eq!(assignment.to_dense(which).unwrap(), dense);
+        }
+
+        // Ensure a private variable that is not in the assignment is rejected, rather than given a new column.
+        let mut invalid = assignment;
+        invalid.constraints[2].2.terms.insert(AssignmentVariable::Private(3), Fr::one());
+        assert!(matches!(invalid.to_matrices(), Err(SynthesisError::AssignmentMismatch(_))));
+    }
+
+    #[test]
+    fn test_sparsity_pattern() {
+        let mut assignment = super::super::tests::create_example_assignment::<Fr>();
+
+        // The wires are the constant, the constant `1` and the output of the first system, and then `x`, `x^2`, `x^3`.
+        let (a, b, c) = assignment.sparsity_pattern().unwrap();
+        assert_eq!(vec![true, false, false, true, true, true], a);
+        assert_eq!(vec![true, false, false, true, false, false], b);
+        assert_eq!(vec![false, false, true, false, true, true], c);
+
+        // Ensure the patterns match the columns of the matrices.
+        let (a_triplets, b_triplets, c_triplets) = assignment.to_matrices().unwrap();
+        for (pattern, triplets) in [(a, a_triplets), (b, b_triplets), (c, c_triplets)] {
+            let columns = triplets.into_iter().map(|(_, column, _)| column).collect::<IndexSet<_>>();
+            assert_eq!(pattern, (0..6).map(|column| columns.contains(&column)).collect::<Vec<_>>());
+        }
+
+        // Ensure the lookup constraints are included, and zero coefficients are not.
+        let (x, y) = (AssignmentVariable::Public(0), AssignmentVariable::Private(0));
+        let lc = |variable, coefficient| AssignmentLC { constant: Fr::zero(), terms: [(variable, coefficient)].into() };
+        assignment.tables.push(LookupTable::default());
+        assignment.lookup_constraints.push((lc(x, Fr::from(2u64)), lc(y.clone(), Fr::zero()), lc(y, Fr::zero()), 0));
+        let (a, b, c) = assignment.sparsity_pattern().unwrap();
+        assert_eq!(vec![true, true, false, true, true, true], a);
+        assert_eq!(vec![true, false, false, true, false, false], b);
+        assert_eq!(vec![false, false, true, false, true, true], c);
+
+        // Ensure a reference to a variable that is not in the assignment is rejected.
+        assignment.constraints[0].0.terms.insert(AssignmentVariable::Private(3), Fr::one());
+        assert!(assignment.sparsity_pattern().is_err());
+        assignment.constraints[0].0.terms.swap_remove(&AssignmentVariable::Private(3));
+        assignment.constraints[0].0.terms.insert(AssignmentVariable::Public(2), Fr::one());
+        assert!(assignment.sparsity_pattern().is_err());
+    }
+
+    #[test]
+    fn test_dense_matrices() {
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+
+        // Round-trip the assignment through its dense matrices.
+        let [a, b, c] = [Matrix::A, Matrix::B, Matrix::C].map(|which| assignment.to_dense(which).unwrap());
+        assert_eq!(3, a.len());
+        assert!(a.iter().chain(&b).chain(&c).all(|row| row.len() == 6));
+        let public = assignment.public.values().copied().collect::<Vec<_>>();
+        let private = assignment.private.values().copied().collect::<Vec<_>>();
+        let candidate =
+            Assignment::from_dense_matrices(a.clone(), b.clone(), c.clone(), public.clone(), private.clone()).unwrap();
+        assert!(candidate.is_satisfied());
+        assert!(assignment.is_key_compatible(&candidate));
+        assert!(assignment.changed_constraints(&candidate).unwrap().is_empty());
+
+        // Ensure mismatched dimensions are rejected.
+        assert!(Assignment::from_dense_matrices(a.clone(), b.clone(), vec![], public.clone(), private.clone()).is_err());
+        let mut short = c.clone();
+        short[1].pop();
+        assert!(Assignment::from_dense_matrices(a, b, short, public, private).is_err());
+
+        // Ensure a public variable that is not in the assignment is rejected, rather than aliasing a private column.
+        let mut invalid = assignment;
+        invalid.constraints[0].0.terms.insert(AssignmentVariable::Public(2), Fr::one());
+        assert!(matches!(invalid.to_dense(Matrix::A), Err(SynthesisError::AssignmentMismatch(_))));
+    }
+}
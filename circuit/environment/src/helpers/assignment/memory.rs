@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::mem::size_of;
+
+/// Returns an estimate of the heap memory used by the given map, in bytes.
+/// This accounts for the entries, their cached hashes, and the index table of the map.
+fn index_map_bytes<K, V>(map: &IndexMap<K, V>) -> usize {
+    map.capacity().saturating_mul(size_of::<(K, V)>() + 2 * size_of::<usize>())
+}
+
+impl<F: Field> AssignmentLC<F> {
+    /// Releases any spare capacity held by the terms of the linear combination.
+    fn shrink_to_fit(&mut self) {
+        self.terms.shrink_to_fit();
+    }
+
+    /// Returns an estimate of the heap memory used by the terms of the linear combination, in bytes.
+    fn heap_bytes(&self) -> usize {
+        index_map_bytes(&self.terms)
+    }
+}
+
+impl<F: Field> Assignment<F> {
+    /// Releases any spare capacity held by the variables, constraints, and lookup tables of the assignment.
+    ///
+    /// This is worthwhile for assignments that are kept around, such as the base assignments shared through
+    /// [`SameCircuitAssignment`]. An assignment converted from an `R1CS` is already allocated to size, whereas
+    /// an assignment that was grown or pruned incrementally may hold up to twice the memory it needs.
+    pub fn shrink_to_fit(&mut self) {
+        self.public.shrink_to_fit();
+        self.private.shrink_to_fit();
+
+        self.constraints.shrink_to_fit();
+        for (a, b, c) in self.constraints.iter_mut() {
+            a.shrink_to_fit();
+            b.shrink_to_fit();
+            c.shrink_to_fit();
+        }
+
+        self.tables.shrink_to_fit();
+        for table in self.tables.iter_mut() {
+            table.table.shrink_to_fit();
+        }
+
+        self.lookup_constraints.shrink_to_fit();
+        for (a, b, c, _) in self.lookup_constraints.iter_mut() {
+            a.shrink_to_fit();
+            b.shrink_to_fit();
+            c.shrink_to_fit();
+        }
+    }
+
+    /// Returns an estimate of the memory used by the assignment, in bytes, for cache-sizing decisions.
+    ///
+    /// This sums the size of the assignment itself, and the capacity-based heap sizes of its variables,
+    /// constraints (including the terms of each linear combination), lookup tables, and lookup constraints.
+    /// The estimate does not account for the overhead of the allocator.
+    pub fn memory_footprint(&self) -> usize {
+        type Constraint<F> = (AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>);
+        type LookupConstraint<F> = (AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>, usize);
+
+        let constraint_bytes = self.constraints.capacity().saturating_mul(size_of::<Constraint<F>>());
+        let lookup_constraint_bytes =
+            self.lookup_constraints.capacity().saturating_mul(size_of::<LookupConstraint<F>>());
+        let term_bytes = self.linear_combinations().map(AssignmentLC::heap_bytes).fold(0, usize::saturating_add);
+        let table_bytes = self
+            .tables
+            .iter()
+            .map(|table| index_map_bytes(&table.table))
+            .fold(self.tables.capacity().saturating_mul(size_of::<LookupTable<F>>()), usize::saturating_add);
+
+        [
+            size_of::<Self>(),
+            index_map_bytes(&self.public),
+            index_map_bytes(&self.private),
+            constraint_bytes,
+            lookup_constraint_bytes,
+            term_bytes,
+            table_bytes,
+        ]
+        .into_iter()
+        .fold(0, usize::saturating_add)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F: Field> SameCircuitAssignment<F> {
+    /// Returns an estimate of the heap memory used by the variables of this assignment, in bytes.
+    /// The base assignment is shared across all assignments of the same circuit, and is not included.
+    pub fn estimate_memory_bytes(&self) -> usize {
+        match &self.variables {
+            Some(variables) => index_map_bytes(&variables.public).saturating_add(index_map_bytes(&variables.private)),
+            None => 0,
+        }
+    }
+
+    /// Returns the given witnesses as assignments of the base circuit, grouped into sub-batches
+    /// whose combined estimated memory does not exceed `max_memory_bytes`.
+    /// A witness that exceeds `max_memory_bytes` on its own is placed in its own sub-batch.
+    /// Returns an error if a witness does not have the same shape as the base.
+    pub fn chunk_batch(
+        witnesses: Vec<Assignment<F>>,
+        base: Arc<Assignment<F>>,
+        max_memory_bytes: usize,
+    ) -> Result<Vec<Vec<Self>>> {
+        let mut batches = Vec::new();
+        let mut batch = Vec::new();
+        let mut batch_bytes = 0usize;
+
+        // Prepare the converter once, to be shared by every assignment.
+        let converter = Arc::new(PreparedConverter::new(&base));
+        for witness in witnesses {
+            let assignment = Self::create_with_converter(base.clone(), converter.clone(), witness)?;
+            let bytes = assignment.estimate_memory_bytes();
+            // If the witness does not fit in the current sub-batch, start a new sub-batch.
+            if !batch.is_empty() && batch_bytes.saturating_add(bytes) > max_memory_bytes {
+                batches.push(core::mem::take(&mut batch));
+                batch_bytes = 0;
+            }
+            batch.push(assignment);
+            batch_bytes = batch_bytes.saturating_add(bytes);
+        }
+        if !batch.is_empty() {
+            batches.push(batch);
+        }
+        Ok(batches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let mut assignment = super::super::tests::create_example_assignment::<Fr>();
+        assignment.private.reserve(1024);
+        assignment.constraints.reserve(1024);
+        assignment.constraints[0].0.terms.reserve(1024);
+        let expected = assignment.clone();
+
+        assignment.shrink_to_fit();
+        assert!(assignment.private.capacity() < 1024);
+        assert!(assignment.constraints.capacity() < 1024);
+        assert!(assignment.constraints[0].0.terms.capacity() < 1024);
+
+        // Ensure the assignment is unchanged.
+        assert_eq!(expected, assignment);
+    }
+
+    #[test]
+    fn test_memory_footprint() {
+        let mut assignment = super::super::tests::create_example_assignment::<Fr>();
+        assignment.shrink_to_fit();
+        let footprint = assignment.memory_footprint();
+        assert!(footprint > size_of::<Assignment<Fr>>());
+
+        // Ensure the footprint tracks the spare capacity of the variables, constraints, and terms.
+        assignment.private.reserve(1024);
+        let with_variables = assignment.memory_footprint();
+        assert!(with_variables >= footprint + 1024 * size_of::<(Index, Fr)>());
+        assignment.constraints[0].0.terms.reserve(1024);
+        let with_terms = assignment.memory_footprint();
+        assert!(with_terms >= with_variables + 1024 * size_of::<(AssignmentVariable<Fr>, Fr)>());
+
+        // Ensure the footprint is restored once the spare capacity is released.
+        assignment.shrink_to_fit();
+        assert_eq!(footprint, assignment.memory_footprint());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_chunk_batch() {
+        let base = Arc::new(super::super::tests::create_example_assignment::<Fr>());
+        let witness_bytes =
+            SameCircuitAssignment::create_with_base(base.clone(), (*base).clone()).unwrap().estimate_memory_bytes();
+        assert!(witness_bytes > 0);
+
+        // Ensure each sub-batch stays under the limit.
+        let max_memory_bytes = 2 * witness_bytes;
+        let witnesses = vec![(*base).clone(); 5];
+        let batches = SameCircuitAssignment::chunk_batch(witnesses, base.clone(), max_memory_bytes).unwrap();
+        assert_eq!(vec![2, 2, 1], batches.iter().map(|batch| batch.len()).collect::<Vec<_>>());
+        for batch in &batches {
+            let batch_bytes: usize = batch.iter().map(|assignment| assignment.estimate_memory_bytes()).sum();
+            assert!(batch_bytes <= max_memory_bytes);
+        }
+
+        // Ensure a witness exceeding the limit is placed in its own sub-batch.
+        let witnesses = vec![(*base).clone(); 2];
+        let batches = SameCircuitAssignment::chunk_batch(witnesses, base, 1).unwrap();
+        assert_eq!(vec![1, 1], batches.iter().map(|batch| batch.len()).collect::<Vec<_>>());
+    }
+}
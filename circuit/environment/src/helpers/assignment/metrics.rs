@@ -0,0 +1,228 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The sizes of an assignment that determine the maximum degree of the polynomials in Varuna,
+/// in the order of the arguments of `AHPForR1CS::max_degree`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct QapDegreeEstimate {
+    /// The number of constraints, including the lookup constraints.
+    pub num_constraints: usize,
+    /// The number of variables, including the padded public variables.
+    pub num_variables: usize,
+    /// The largest number of nonzeros in the `A`, `B`, and `C` matrices.
+    pub num_non_zero: usize,
+}
+
+impl<F: Field> Assignment<F> {
+    /// Returns the sizes of the assignment as indexed by Varuna, to derive the maximum degree for the universal setup.
+    ///
+    /// This mirrors the current indexer in Varuna, which pads the public variables (including the constant wire)
+    /// up to the next power of two, and adds one constraint over three private variables for zero knowledge.
+    /// The zero-knowledge constraint is always included, so the sizes are exact in hiding mode and may otherwise
+    /// be slightly larger. The nonzeros are counted from the terms, so terms with a zero coefficient or repeated
+    /// variables (see [`Assignment::reduce_all`]) may also lead to larger sizes, but never smaller.
+    pub fn to_qap_degree_estimate(&self) -> QapDegreeEstimate {
+        // The zero-knowledge constraint `r_0 * r_1 == r_2` adds one nonzero to each matrix.
+        const NUM_ZK_VARIABLES: usize = 3;
+
+        let num_constraints = self.constraints.len() + self.lookup_constraints.len() + 1;
+        let num_variables = (self.public.len() + 1).next_power_of_two() + self.private.len() + NUM_ZK_VARIABLES;
+        let (num_nonzeros_a, num_nonzeros_b, num_nonzeros_c) = self.num_nonzeros();
+        let num_non_zero = num_nonzeros_a.max(num_nonzeros_b).max(num_nonzeros_c) as usize + 1;
+
+        QapDegreeEstimate { num_constraints, num_variables, num_non_zero }
+    }
+
+    /// Returns an estimate of the largest FFT domain size that Varuna will select for the assignment.
+    ///
+    /// This mirrors the current domain selection in Varuna, which rounds the number of constraints,
+    /// the number of variables, and the largest number of nonzeros up to the next power of two.
+    /// The sizes are those of [`Assignment::to_qap_degree_estimate`], so they include the padding
+    /// and the zero-knowledge constraint added during indexing.
+    pub fn estimated_fft_domain_size(&self) -> usize {
+        let QapDegreeEstimate { num_constraints, num_variables, num_non_zero } = self.to_qap_degree_estimate();
+        num_constraints.max(num_variables).max(num_non_zero).next_power_of_two()
+    }
+
+    /// Returns the length of the longest chain of dependencies between the private variables.
+    ///
+    /// Each private variable is considered to be defined by the first constraint that references it,
+    /// and to depend on the private variables that were defined earlier and appear in that constraint.
+    /// Shallow assignments are better suited to parallel witness generation.
+    pub fn dependency_depth(&self) -> u64 {
+        // Maps each private variable to its depth.
+        let mut depths = IndexMap::<Index, u64>::new();
+        for (a, b, c) in &self.constraints {
+            let privates = [a, b, c].into_iter().flat_map(|lc| lc.terms.keys()).filter_map(|variable| match variable {
+                AssignmentVariable::Private(index) => Some(*index),
+                _ => None,
+            });
+            // Determine the depth of the variables defined by this constraint.
+            let depth = 1 + privates.clone().filter_map(|index| depths.get(&index)).max().copied().unwrap_or_default();
+            for index in privates {
+                depths.entry(index).or_insert(depth);
+            }
+        }
+        depths.values().max().copied().unwrap_or_default()
+    }
+
+    /// Returns the number of private variables that need to be committed to in zero knowledge.
+    ///
+    /// This counts the distinct private variables that appear with a nonzero coefficient in a constraint
+    /// or lookup constraint. Dead variables, which are never referenced, and hint-only variables,
+    /// which are only referenced with a zero coefficient, do not affect the constraints and are excluded.
+    pub fn zk_witness_size(&self) -> usize {
+        self.linear_combinations()
+            .flat_map(|lc| lc.terms.iter())
+            .filter_map(|(variable, coefficient)| match variable {
+                AssignmentVariable::Private(index) if !coefficient.is_zero() => Some(*index),
+                _ => None,
+            })
+            .collect::<alloc::collections::BTreeSet<_>>()
+            .len()
+    }
+
+    /// Returns the number of constraints in which both `A` and `B` are non-constant.
+    /// These constraints require a genuine multiplication, whereas a constraint with a constant side is linear.
+    pub fn num_multiplication_gates(&self) -> u64 {
+        self.constraints.iter().filter(|(a, b, _)| !a.is_effectively_constant() && !b.is_effectively_constant()).count()
+            as u64
+    }
+
+    /// Returns the average number of nonzeros per row of the `A`, `B`, and `C` matrices,
+    /// counting a row for each constraint and lookup constraint, or `0.0` if there are no constraints.
+    /// Denser assignments are more expensive to index and prove with Varuna.
+    pub fn constraint_density(&self) -> f64 {
+        let num_rows = self.num_constraints().saturating_add(self.num_lookup_constraints());
+        if num_rows == 0 {
+            return 0.0;
+        }
+        // Sum in floating point, as the (saturated) counts may overflow a `u64` when added.
+        let (num_nonzeros_a, num_nonzeros_b, num_nonzeros_c) = self.num_nonzeros();
+        (num_nonzeros_a as f64 + num_nonzeros_b as f64 + num_nonzeros_c as f64) / (3.0 * num_rows as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit::prelude::{Circuit, Environment};
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+
+    #[test]
+    fn test_estimated_fft_domain_size() {
+        let _candidate_output = super::super::tests::create_example_circuit::<Circuit>();
+        let assignment = Circuit::eject_assignment_and_reset();
+
+        let domain_size = assignment.estimated_fft_domain_size();
+        assert!(domain_size.is_power_of_two());
+        assert!(domain_size as u64 >= assignment.num_constraints());
+
+        // Ensure the domain covers the largest of the indexed sizes, which is the 10 padded variables.
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+        assert_eq!(16, assignment.estimated_fft_domain_size());
+    }
+
+    #[test]
+    fn test_to_qap_degree_estimate() {
+        use snarkvm_algorithms::{
+            crypto_hash::PoseidonSponge,
+            snark::varuna::{ahp::AHPForR1CS, VarunaHidingMode, VarunaSNARK},
+            SNARK,
+        };
+        use snarkvm_curves::bls12_377::{Bls12_377, Fq};
+
+        type VarunaInst = VarunaSNARK<Bls12_377, PoseidonSponge<Fq, 2, 1>, VarunaHidingMode>;
+
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+        let estimate = assignment.to_qap_degree_estimate();
+        assert_eq!(QapDegreeEstimate { num_constraints: 4, num_variables: 10, num_non_zero: 6 }, estimate);
+
+        // Ensure the derived maximum degree suffices to set up the circuit.
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(
+            estimate.num_constraints,
+            estimate.num_variables,
+            estimate.num_non_zero,
+        )
+        .unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let (_, index_vk) = VarunaInst::circuit_setup(&universal_srs, &assignment).unwrap();
+
+        // Ensure the estimate matches the indexed circuit.
+        let info = index_vk.circuit_info;
+        assert_eq!(info.num_constraints, estimate.num_constraints);
+        assert_eq!(info.num_variables, estimate.num_variables);
+        assert_eq!(info.num_non_zero_a.max(info.num_non_zero_b).max(info.num_non_zero_c), estimate.num_non_zero);
+        assert_eq!(info.max_degree::<Fr, VarunaHidingMode>(), max_degree);
+    }
+
+    #[test]
+    fn test_dependency_depth() {
+        // Construct a chain `x_{i+1} = x_i * x_i`.
+        let chain_length = 4;
+        let variable = |index| AssignmentLC {
+            constant: Fr::zero(),
+            terms: [(AssignmentVariable::Private(index), Fr::one())].into(),
+        };
+        let mut assignment = super::super::tests::create_example_assignment::<Fr>();
+        assignment.private = (0..=chain_length).map(|i| (i, Fr::from(2u64).pow([1 << i]))).collect();
+        assignment.constraints = (0..chain_length).map(|i| (variable(i), variable(i), variable(i + 1))).collect();
+        assert!(assignment.is_satisfied());
+        assert_eq!(chain_length, assignment.dependency_depth());
+
+        // Ensure a constraint on a shallow variable does not increase the depth.
+        assignment.private.insert(chain_length + 1, Fr::one());
+        assignment.constraints.push((variable(chain_length + 1), variable(0), variable(0)));
+        assert!(assignment.is_satisfied());
+        assert_eq!(chain_length, assignment.dependency_depth());
+    }
+
+    #[test]
+    fn test_zk_witness_size() {
+        let mut assignment = super::super::tests::create_example_assignment::<Fr>();
+        assert_eq!(assignment.num_private() as usize, assignment.zk_witness_size());
+
+        // Add a dead variable, and a hint-only variable.
+        assignment.private.insert(3, Fr::from(4u64));
+        assignment.private.insert(4, Fr::from(5u64));
+        assignment.constraints[0].0.terms.insert(AssignmentVariable::Private(4), Fr::zero());
+        assert!(assignment.is_satisfied());
+        assert_eq!(5, assignment.num_private());
+        assert_eq!(3, assignment.zk_witness_size());
+    }
+
+    #[test]
+    fn test_num_multiplication_gates() {
+        // The example computes `x * x` and `x^2 * x`, and enforces the sum `x^3 + x + 5` with a linear constraint.
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+        assert_eq!(3, assignment.num_constraints());
+        assert_eq!(2, assignment.num_multiplication_gates());
+    }
+
+    #[test]
+    fn test_constraint_density() {
+        let mut assignment = super::super::tests::create_example_assignment::<Fr>();
+        let (num_nonzeros_a, num_nonzeros_b, num_nonzeros_c) = assignment.num_nonzeros();
+        let expected = (num_nonzeros_a + num_nonzeros_b + num_nonzeros_c) as f64 / 9.0;
+        assert_eq!(expected, assignment.constraint_density());
+        assert!(assignment.constraint_density() > 1.0);
+
+        // Ensure an assignment without constraints has a density of zero.
+        assignment.constraints.clear();
+        assert_eq!(0.0, assignment.constraint_density());
+    }
+}
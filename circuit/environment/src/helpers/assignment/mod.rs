@@ -0,0 +1,2038 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod arithmetic;
+mod audit;
+mod builder;
+mod bytes;
+#[cfg(feature = "std")]
+mod cache;
+mod canonical;
+mod coefficients;
+mod commitment;
+mod compatible;
+mod concat;
+mod converter;
+mod diff;
+mod digest;
+mod display;
+mod filter;
+#[cfg(feature = "std")]
+mod fixture;
+mod lookup;
+mod matrices;
+mod memory;
+mod metrics;
+mod no_lookup;
+mod optimize;
+mod recording;
+mod satisfy;
+#[cfg(feature = "serde")]
+mod serialize;
+mod validate;
+mod witness;
+
+pub use audit::{AuditThresholds, AuditWarning};
+#[cfg(feature = "std")]
+pub use cache::ProvingKeyCache;
+pub use coefficients::CoefficientStats;
+pub use converter::PreparedConverter;
+pub use diff::AssignmentDiff;
+pub use matrices::Matrix;
+pub use metrics::QapDegreeEstimate;
+pub use no_lookup::NoLookupAssignment;
+pub use recording::RecordingConstraintSystem;
+pub use satisfy::UnsatisfiedConstraint;
+pub use validate::ValidatedAssignment;
+
+use alloc::{format, string::String, sync::Arc, vec::Vec};
+
+use crate::{
+    prelude::{ensure, Result},
+    Index,
+    Mode,
+};
+use snarkvm_algorithms::r1cs::LookupTable;
+use snarkvm_fields::{Field, PrimeField};
+
+use indexmap::{IndexMap, IndexSet};
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = "", tag = "type", content = "value", rename_all = "lowercase"))]
+pub enum AssignmentVariable<F: Field> {
+    Constant(F),
+    Public(Index),
+    Private(Index),
+}
+
+impl<F: Field> AssignmentVariable<F> {
+    /// Returns `true` if the variable is a constant.
+    pub const fn is_constant(&self) -> bool {
+        matches!(self, Self::Constant(..))
+    }
+
+    /// Returns `true` if the variable is public.
+    pub const fn is_public(&self) -> bool {
+        matches!(self, Self::Public(..))
+    }
+
+    /// Returns `true` if the variable is private.
+    pub const fn is_private(&self) -> bool {
+        matches!(self, Self::Private(..))
+    }
+
+    /// Returns the index of the variable, or `None` if the variable is a constant.
+    pub const fn index(&self) -> Option<Index> {
+        match self {
+            Self::Constant(..) => None,
+            Self::Public(index) | Self::Private(index) => Some(*index),
+        }
+    }
+}
+
+impl<F: PrimeField> From<&crate::Variable<F>> for AssignmentVariable<F> {
+    /// Converts a variable to an assignment variable.
+    fn from(variable: &crate::Variable<F>) -> Self {
+        match variable {
+            crate::Variable::Constant(value) => Self::Constant(**value),
+            crate::Variable::Public(index, _) => Self::Public(*index),
+            crate::Variable::Private(index, _) => Self::Private(*index),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(bound = ""))]
+pub struct AssignmentLC<F: Field> {
+    constant: F,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize::serialize_terms", deserialize_with = "serialize::deserialize_terms")
+    )]
+    terms: IndexMap<AssignmentVariable<F>, F>,
+}
+
+impl<F: PrimeField> From<&crate::LinearCombination<F>> for AssignmentLC<F> {
+    /// Converts a linear combination to an assignment linear combination.
+    fn from(lc: &crate::LinearCombination<F>) -> Self {
+        Self {
+            constant: lc.to_constant(),
+            terms: FromIterator::from_iter(
+                lc.to_terms().iter().map(|(variable, coefficient)| (variable.into(), *coefficient)),
+            ),
+        }
+    }
+}
+
+impl<F: Field> Extend<(AssignmentVariable<F>, F)> for AssignmentLC<F> {
+    /// Adds the given terms to the linear combination, summing the coefficients of repeated variables.
+    /// A variable whose coefficient is (or sums to) zero is not kept as a term.
+    fn extend<I: IntoIterator<Item = (AssignmentVariable<F>, F)>>(&mut self, terms: I) {
+        for (variable, coefficient) in terms {
+            match self.terms.entry(variable) {
+                indexmap::map::Entry::Occupied(mut entry) => {
+                    *entry.get_mut() += coefficient;
+                    if entry.get().is_zero() {
+                        entry.shift_remove();
+                    }
+                }
+                indexmap::map::Entry::Vacant(entry) => {
+                    if !coefficient.is_zero() {
+                        entry.insert(coefficient);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<F: Field> PartialEq for AssignmentLC<F> {
+    /// Returns `true` if the linear combinations have the same constant and terms, irrespective of the order of the terms.
+    fn eq(&self, other: &Self) -> bool {
+        self.constant == other.constant && self.terms == other.terms
+    }
+}
+
+impl<F: Field> Eq for AssignmentLC<F> {}
+
+impl<F: Field> AssignmentLC<F> {
+    /// Initializes a linear combination from the given constant and terms.
+    /// The coefficients of repeated variables are summed, and variables with a zero coefficient are dropped.
+    pub fn from_terms(constant: F, terms: impl IntoIterator<Item = (AssignmentVariable<F>, F)>) -> Self {
+        let mut lc = Self { constant, terms: IndexMap::new() };
+        lc.extend(terms);
+        lc
+    }
+
+    /// Returns the constant term of the linear combination.
+    pub const fn constant(&self) -> F {
+        self.constant
+    }
+
+    /// Returns the terms of the linear combination.
+    pub const fn terms(&self) -> &IndexMap<AssignmentVariable<F>, F> {
+        &self.terms
+    }
+
+    /// Returns the coefficient of the given variable in the linear combination,
+    /// or zero if the variable is not a term of the linear combination.
+    pub fn get_coefficient(&self, variable: &AssignmentVariable<F>) -> F {
+        self.terms.get(variable).copied().unwrap_or_else(F::zero)
+    }
+
+    /// Returns `true` if the given variable is a term of the linear combination.
+    pub fn contains(&self, variable: &AssignmentVariable<F>) -> bool {
+        self.terms.contains_key(variable)
+    }
+
+    /// Returns `true` if the linear combination has no terms.
+    pub fn is_constant(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Returns the constant term of the linear combination, if the linear combination has no terms.
+    pub fn as_constant(&self) -> Option<F> {
+        match self.is_constant() {
+            true => Some(self.constant),
+            false => None,
+        }
+    }
+
+    /// Returns `true` if the linear combination has no nonzero terms.
+    pub(super) fn is_effectively_constant(&self) -> bool {
+        self.terms.values().all(|coefficient| coefficient.is_zero())
+    }
+
+    /// Returns the number of terms in the linear combination, excluding the constant.
+    /// Unlike [`AssignmentLC::num_nonzeros`], a nonzero constant is not counted.
+    pub fn num_terms(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Returns the number of nonzeros in the linear combination, which is its number of terms,
+    /// plus one if the constant is nonzero (as the constant is a coefficient of the constant `1` variable).
+    /// This is the number of entries of the linear combination in its row of the R1CS matrices.
+    pub fn num_nonzeros(&self) -> u64 {
+        // Increment by one if the constant is nonzero.
+        match self.constant.is_zero() {
+            true => self.terms.len() as u64,
+            false => (self.terms.len() as u64).saturating_add(1),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+struct PubAndPrivVariables<F: Field> {
+    pub(crate) public: IndexMap<Index, F>,
+    pub(crate) private: IndexMap<Index, F>,
+}
+
+/// A struct like Assignment, but for memory saving.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub struct SameCircuitAssignment<F: Field> {
+    variables: Option<PubAndPrivVariables<F>>,
+    base: Arc<Assignment<F>>,
+    converter: Arc<PreparedConverter>,
+}
+
+#[cfg(feature = "std")]
+impl<F: Field> SameCircuitAssignment<F> {
+    /// The caller should ensure that base and another are same circuit and may with different variable values.
+    /// Returns an error if base and another do not have the same shape (see [`Assignment::verify_shape_matches`]).
+    pub fn create_with_base(
+        base: Arc<Assignment<F>>,
+        another: Assignment<F>,
+    ) -> Result<Self, snarkvm_algorithms::r1cs::SynthesisError> {
+        let converter = Arc::new(PreparedConverter::new(&base));
+        Self::create_with_converter(base, converter, another)
+    }
+
+    /// Returns an assignment of the same base, with the variables of the given witness.
+    /// Unlike [`SameCircuitAssignment::create_with_base`], this reuses the prepared converter of `self`,
+    /// and likewise returns an error if the witness does not have the same shape as the base.
+    pub fn with_witness(&self, witness: Assignment<F>) -> Result<Self, snarkvm_algorithms::r1cs::SynthesisError> {
+        Self::create_with_converter(self.base.clone(), self.converter.clone(), witness)
+    }
+
+    /// Returns an assignment of the given base and prepared converter, with the variables of the given witness.
+    pub(super) fn create_with_converter(
+        base: Arc<Assignment<F>>,
+        converter: Arc<PreparedConverter>,
+        another: Assignment<F>,
+    ) -> Result<Self, snarkvm_algorithms::r1cs::SynthesisError> {
+        base.verify_shape_matches(&another)?;
+        Ok(Self {
+            variables: Some(PubAndPrivVariables { public: another.public, private: another.private }),
+            base,
+            converter,
+        })
+    }
+
+    /// Returns an assignment of the given base, with the variables of the given witness.
+    /// This is shorthand for [`Assignment::to_same_circuit_base`] followed by [`SameCircuitAssignment::create_with_base`],
+    /// and likewise returns an error if the witness does not have the same shape as the base.
+    pub fn from_base_and_witness(
+        base: Assignment<F>,
+        witness: Assignment<F>,
+    ) -> Result<Self, snarkvm_algorithms::r1cs::SynthesisError> {
+        Self::create_with_base(base.to_same_circuit_base(), witness)
+    }
+
+    /// Returns one assignment per witness, all sharing the given base and a single prepared converter.
+    /// Returns an error identifying the first witness that does not have the same shape as the base
+    /// (see [`Assignment::verify_shape_matches`]).
+    pub fn create_with_bases(
+        base: Arc<Assignment<F>>,
+        witnesses: Vec<Assignment<F>>,
+    ) -> Result<Vec<Self>, snarkvm_algorithms::r1cs::SynthesisError> {
+        use snarkvm_algorithms::r1cs::SynthesisError;
+
+        // Prepare the converter once, to be shared by every assignment.
+        let converter = Arc::new(PreparedConverter::new(&base));
+        witnesses
+            .into_iter()
+            .enumerate()
+            .map(|(i, witness)| {
+                Self::create_with_converter(base.clone(), converter.clone(), witness).map_err(|error| match error {
+                    SynthesisError::AssignmentMismatch(message) => {
+                        SynthesisError::AssignmentMismatch(format!("Witness {i}: {message}"))
+                    }
+                    error => error,
+                })
+            })
+            .collect()
+    }
+
+    pub fn single_one(base: Assignment<F>) -> Self {
+        let converter = Arc::new(PreparedConverter::new(&base));
+        Self { variables: None, base: Arc::new(base), converter }
+    }
+
+    /// Returns the public inputs of the assignment.
+    pub fn public_inputs(&self) -> &IndexMap<Index, F> {
+        match &self.variables {
+            Some(v) => &v.public,
+            None => &self.base.public,
+        }
+    }
+
+    /// Returns the private inputs of the assignment.
+    pub fn private_inputs(&self) -> &IndexMap<Index, F> {
+        match &self.variables {
+            Some(v) => &v.private,
+            None => &self.base.private,
+        }
+    }
+
+    /// Returns the number of public variables in the assignment.
+    pub fn num_public(&self) -> u64 {
+        self.public_inputs().len() as u64
+    }
+
+    /// Returns the number of private variables in the assignment.
+    pub fn num_private(&self) -> u64 {
+        self.private_inputs().len() as u64
+    }
+
+    /// Returns the number of constraints in the base assignment, which is shared by every assignment of the same circuit.
+    pub fn num_constraints(&self) -> u64 {
+        self.base.num_constraints()
+    }
+
+    /// Returns the number of lookup constraints in the base assignment.
+    pub fn num_lookup_constraints(&self) -> u64 {
+        self.base.num_lookup_constraints()
+    }
+
+    /// Returns the number of nonzeros in the base assignment (see [`Assignment::num_nonzeros`]).
+    pub fn num_nonzeros(&self) -> (u64, u64, u64) {
+        self.base.num_nonzeros()
+    }
+
+    /// Returns the base assignment, which is shared by every assignment of the same circuit.
+    pub const fn base(&self) -> &Arc<Assignment<F>> {
+        &self.base
+    }
+
+    /// Returns the constraints of the base assignment.
+    pub fn constraints(&self) -> &Vec<(AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>)> {
+        self.base.constraints()
+    }
+}
+
+/// Returns the given variable index, plus the given offset, as a `usize`,
+/// or an `Overflow` error if it does not fit, such as on a 32-bit target.
+fn to_usize_index(index: Index, offset: Index) -> Result<usize, snarkvm_algorithms::r1cs::SynthesisError> {
+    index.checked_add(offset).and_then(|index| usize::try_from(index).ok()).ok_or_else(|| {
+        snarkvm_algorithms::r1cs::SynthesisError::Overflow(format!(
+            "The variable index {index} (with an offset of {offset}) does not fit in a usize"
+        ))
+    })
+}
+
+#[cfg(feature = "std")]
+impl<F: Field> snarkvm_algorithms::r1cs::ConstraintSynthesizer<F> for SameCircuitAssignment<F> {
+    /// Synthesizes the constraints from the environment into a `snarkvm_algorithms::r1cs`-compliant constraint system.
+    fn generate_constraints<CS: snarkvm_algorithms::r1cs::ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+    ) -> Result<(), snarkvm_algorithms::r1cs::SynthesisError> {
+        // Synthesize the base with the variables of the witness, reusing the converter prepared from the base.
+        let variables = SynthesisVariables {
+            public: self.public_inputs(),
+            private: self.private_inputs(),
+            converter: Some(&self.converter),
+        };
+        self.base.synthesize(cs, variables, true, &SynthesisNames::default(), Default::default(), |_, _| {})
+    }
+}
+
+/// A struct that contains public variable assignments, private variable assignments,
+/// and constraint assignments.
+///
+/// Building without the `std` feature (e.g. with `--no-default-features`) removes `SameCircuitAssignment`
+/// (with its memory estimates and batching), `ProvingKeyCache`, the fixture files (`Assignment::write_fixture`
+/// and `Assignment::read_fixture`), and the circom export (`Assignment::write_r1cs` and `Assignment::write_witness`).
+/// The rest of the assignment API is unchanged. The crate is not `no_std` yet: it still requires `std`, not least
+/// because the lookup tables of an assignment come from `snarkvm_algorithms`, which requires `std`.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(bound = ""))]
+pub struct Assignment<F: Field> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "serialize::serialize_variables",
+            deserialize_with = "serialize::deserialize_variables"
+        )
+    )]
+    pub(crate) public: IndexMap<Index, F>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "serialize::serialize_variables",
+            deserialize_with = "serialize::deserialize_variables"
+        )
+    )]
+    pub(crate) private: IndexMap<Index, F>,
+    pub(crate) constraints: Vec<(AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>)>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize::serialize_tables", deserialize_with = "serialize::deserialize_tables")
+    )]
+    pub(crate) tables: Vec<LookupTable<F>>,
+    pub(crate) lookup_constraints: Vec<(AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>, usize)>,
+    pub(crate) circuit_version: Option<u64>,
+}
+
+impl<F: Field> PartialEq for Assignment<F> {
+    /// Returns `true` if the assignments have the same variables, constraints, lookup tables, lookup constraints,
+    /// and circuit version. The order of the variables, of the terms, and of the lookup table entries is ignored.
+    fn eq(&self, other: &Self) -> bool {
+        self.public == other.public
+            && self.private == other.private
+            && self.constraints == other.constraints
+            && self.has_same_tables(other)
+            && self.lookup_constraints == other.lookup_constraints
+            && self.circuit_version == other.circuit_version
+    }
+}
+
+impl<F: Field> Eq for Assignment<F> {}
+
+impl<F: PrimeField> From<crate::R1CS<F>> for Assignment<F> {
+    /// Converts an R1CS to an assignment.
+    ///
+    /// The variables, constraints, and lookup constraints are allocated to the dimensions of the R1CS up front.
+    fn from(r1cs: crate::R1CS<F>) -> Self {
+        let (public_variables, private_variables) = (r1cs.to_public_variables(), r1cs.to_private_variables());
+        let (r1cs_constraints, r1cs_lookup_constraints) = (r1cs.to_constraints(), r1cs.to_lookup_constraints());
+
+        let mut public = IndexMap::with_capacity(public_variables.len());
+        public.extend(public_variables.iter().map(|variable| (variable.index(), variable.value())));
+        let mut private = IndexMap::with_capacity(private_variables.len());
+        private.extend(private_variables.iter().map(|variable| (variable.index(), variable.value())));
+
+        let mut constraints = Vec::with_capacity(r1cs_constraints.len());
+        constraints.extend(r1cs_constraints.iter().map(|constraint| {
+            let (a, b, c) = constraint.to_terms();
+            (a.into(), b.into(), c.into())
+        }));
+        let mut lookup_constraints = Vec::with_capacity(r1cs_lookup_constraints.len());
+        lookup_constraints.extend(r1cs_lookup_constraints.iter().map(|constraint| {
+            let (a, b, c, index) = constraint.to_terms();
+            (a.into(), b.into(), c.into(), index)
+        }));
+
+        Self {
+            public,
+            private,
+            constraints,
+            tables: r1cs.to_lookup_tables().clone(),
+            lookup_constraints,
+            circuit_version: None,
+        }
+    }
+}
+
+impl<F: Field> Assignment<F> {
+    /// Returns the public inputs of the assignment.
+    pub const fn public_inputs(&self) -> &IndexMap<Index, F> {
+        &self.public
+    }
+
+    /// Returns the private inputs of the assignment.
+    pub const fn private_inputs(&self) -> &IndexMap<Index, F> {
+        &self.private
+    }
+
+    /// Returns the lookup tables of the assignment.
+    pub fn lookup_tables(&self) -> &[LookupTable<F>] {
+        &self.tables
+    }
+
+    /// Returns the lookup table at the given index, or `None` if it does not exist.
+    pub fn lookup_table(&self, index: usize) -> Option<&LookupTable<F>> {
+        self.tables.get(index)
+    }
+
+    /// Returns the values of the public variables in index order, as expected by the verifier.
+    /// These are exactly the public inputs accepted by `VarunaSNARK::verify` for a proof of this assignment.
+    ///
+    /// Element 0 is the value of the first public variable, which is the constant `1` of the first system.
+    /// The constant `1` that the second system allocates as its own first public variable is not included,
+    /// which is why public variable `i` of the assignment is public variable `i + 1` of the constraint system.
+    pub fn public_input_values(&self) -> Vec<F> {
+        let mut public = self.public.iter().collect::<Vec<_>>();
+        public.sort_unstable_by_key(|(index, _)| **index);
+        public.into_iter().map(|(_, value)| *value).collect()
+    }
+
+    /// Alias of [`Assignment::public_input_values`].
+    pub fn public_input_slice(&self) -> Vec<F> {
+        self.public_input_values()
+    }
+
+    /// Returns the values of the public variables (the instance) and of the private variables (the witness),
+    /// each in index order, which is the order in which the synthesizer allocates them.
+    ///
+    /// The public values are [`Assignment::public_input_values`], so they begin with the constant `1`
+    /// of the first system, and do not include the constant `1` of the second system.
+    pub fn split_public(&self) -> (Vec<F>, Vec<F>) {
+        (self.public_input_values(), self.private_values_by_index())
+    }
+
+    /// Returns an iterator over the variables of the assignment and their values,
+    /// starting with the public variables, followed by the private variables.
+    pub fn variables(&self) -> impl Iterator<Item = (AssignmentVariable<F>, F)> + '_ {
+        let public = self.public.iter().map(|(index, value)| (AssignmentVariable::Public(*index), *value));
+        let private = self.private.iter().map(|(index, value)| (AssignmentVariable::Private(*index), *value));
+        public.chain(private)
+    }
+
+    /// Returns the constraints of the assignment.
+    pub const fn constraints(&self) -> &Vec<(AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>)> {
+        &self.constraints
+    }
+
+    /// Returns the lookup constraints of the assignment, each with the index of the lookup table it targets.
+    pub fn lookup_constraints(&self) -> &[(AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>, usize)] {
+        &self.lookup_constraints
+    }
+
+    /// Returns the lookup constraint at the given index, or `None` if it does not exist.
+    pub fn lookup_constraint(
+        &self,
+        index: usize,
+    ) -> Option<&(AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>, usize)> {
+        self.lookup_constraints.get(index)
+    }
+
+    /// Returns the circuit version of the assignment, if one is set.
+    pub const fn circuit_version(&self) -> Option<u64> {
+        self.circuit_version
+    }
+
+    /// Returns the assignment, tagged with the given circuit version.
+    /// The circuit version should be bumped whenever the logic of the circuit changes.
+    pub fn with_circuit_version(mut self, circuit_version: u64) -> Self {
+        self.circuit_version = Some(circuit_version);
+        self
+    }
+
+    /// Returns a copy of the assignment, with its public variables substituted by the given public variables.
+    /// The indices of the given public variables must match the indices of the existing public variables exactly.
+    pub fn with_public_inputs(
+        &self,
+        new_public: IndexMap<Index, F>,
+    ) -> Result<Self, snarkvm_algorithms::r1cs::SynthesisError> {
+        // Ensure the indices line up, reporting the first index that does not.
+        for position in 0..self.public.len().max(new_public.len()) {
+            match (self.public.get_index(position), new_public.get_index(position)) {
+                (Some((expected, _)), Some((found, _))) if expected == found => continue,
+                (expected, found) => {
+                    return Err(snarkvm_algorithms::r1cs::SynthesisError::AssignmentMismatch(format!(
+                        "Public variable {position} has index {:?}, but the base has index {:?}",
+                        found.map(|(index, _)| index),
+                        expected.map(|(index, _)| index),
+                    )));
+                }
+            }
+        }
+
+        let mut assignment = self.clone();
+        assignment.public = new_public;
+        Ok(assignment)
+    }
+
+    /// Returns a copy of the assignment, with the values of its public and private variables replaced
+    /// by the given values, in order. That is, the `i`-th public value is assigned to the `i`-th public
+    /// variable of the assignment, and likewise for the private values. The constraints, lookup tables,
+    /// lookup constraints, and circuit version are kept. Returns an error if the number of public or
+    /// private values does not match the number of public or private variables.
+    pub fn clone_structure_with_values(
+        &self,
+        public: &[F],
+        private: &[F],
+    ) -> Result<Self, snarkvm_algorithms::r1cs::SynthesisError> {
+        for (mode, expected, found) in
+            [("public", self.public.len(), public.len()), ("private", self.private.len(), private.len())]
+        {
+            if expected != found {
+                return Err(snarkvm_algorithms::r1cs::SynthesisError::AssignmentMismatch(format!(
+                    "Expected {expected} {mode} values, found {found}"
+                )));
+            }
+        }
+
+        Ok(Self {
+            public: self.public.keys().copied().zip(public.iter().copied()).collect(),
+            private: self.private.keys().copied().zip(private.iter().copied()).collect(),
+            constraints: self.constraints.clone(),
+            tables: self.tables.clone(),
+            lookup_constraints: self.lookup_constraints.clone(),
+            circuit_version: self.circuit_version,
+        })
+    }
+
+    /// Returns the assignment as a base for [`SameCircuitAssignment::create_with_base`].
+    /// The base should be synthesized from the same circuit as the witnesses, so that it has the same number of
+    /// public and private variables, as the constraints of the base are shared by every witness.
+    pub fn to_same_circuit_base(self) -> Arc<Self> {
+        Arc::new(self)
+    }
+
+    /// Returns the number of public variables in the assignment.
+    pub fn num_public(&self) -> u64 {
+        self.public.len() as u64
+    }
+
+    /// Returns the number of private variables in the assignment.
+    pub fn num_private(&self) -> u64 {
+        self.private.len() as u64
+    }
+
+    /// Returns the number of constraints in the assignment.
+    pub fn num_constraints(&self) -> u64 {
+        self.constraints.len() as u64
+    }
+
+    /// Returns the number of lookup tables in the assignment.
+    pub fn num_lookup_tables(&self) -> u64 {
+        self.tables.len() as u64
+    }
+
+    /// Returns the number of lookup constraints in the assignment.
+    pub fn num_lookup_constraints(&self) -> u64 {
+        self.lookup_constraints.len() as u64
+    }
+
+    /// Returns the number of nonzeros in the assignment.
+    pub fn num_nonzeros(&self) -> (u64, u64, u64) {
+        self.num_nonzeros_breakdown()
+            .into_iter()
+            .fold((0, 0, 0), |(a, b, c), (x, y, z)| (a.saturating_add(x), b.saturating_add(y), c.saturating_add(z)))
+    }
+
+    /// Returns the number of nonzeros in the assignment, as in [`Assignment::num_nonzeros`].
+    ///
+    /// With the `parallel` feature, the counts of the constraints and lookup constraints are summed in parallel.
+    /// As saturating addition of unsigned integers is associative, the result is the same as the serial count,
+    /// including on overflow. Without the `parallel` feature, this is [`Assignment::num_nonzeros`].
+    pub fn rayon_num_nonzeros(&self) -> (u64, u64, u64) {
+        #[cfg(not(feature = "parallel"))]
+        {
+            self.num_nonzeros()
+        }
+
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+
+            let add = |(a, b, c): (u64, u64, u64), (x, y, z): (u64, u64, u64)| {
+                (a.saturating_add(x), b.saturating_add(y), c.saturating_add(z))
+            };
+            let count = |a: &AssignmentLC<F>, b: &AssignmentLC<F>, c: &AssignmentLC<F>| {
+                (a.num_nonzeros(), b.num_nonzeros(), c.num_nonzeros())
+            };
+            let constraints = self.constraints.par_iter().map(|(a, b, c)| count(a, b, c)).reduce(|| (0, 0, 0), add);
+            let lookup_constraints =
+                self.lookup_constraints.par_iter().map(|(a, b, c, _)| count(a, b, c)).reduce(|| (0, 0, 0), add);
+            add(constraints, lookup_constraints)
+        }
+    }
+
+    /// Returns the number of nonzeros in `A`, `B`, and `C` for each constraint, followed by each lookup constraint.
+    pub fn num_nonzeros_breakdown(&self) -> Vec<(u64, u64, u64)> {
+        self.constraints
+            .iter()
+            .map(|(a, b, c)| (a.num_nonzeros(), b.num_nonzeros(), c.num_nonzeros()))
+            .chain(
+                self.lookup_constraints
+                    .iter()
+                    .map(|(a, b, c, _)| (a.num_nonzeros(), b.num_nonzeros(), c.num_nonzeros())),
+            )
+            .collect()
+    }
+
+    /// Returns the evaluation of the given linear combination under the assignment,
+    /// or `None` if the linear combination references a variable that is not in the assignment.
+    pub fn evaluate_lc(&self, lc: &AssignmentLC<F>) -> Option<F> {
+        lc.terms.iter().try_fold(lc.constant, |sum, (variable, coefficient)| {
+            let value = match variable {
+                AssignmentVariable::Constant(value) => *value,
+                AssignmentVariable::Public(index) => *self.public.get(index)?,
+                AssignmentVariable::Private(index) => *self.private.get(index)?,
+            };
+            Some(sum + *coefficient * value)
+        })
+    }
+
+    /// Returns `true` if the assignments have the same lookup tables, irrespective of the order of the entries.
+    fn has_same_tables(&self, other: &Self) -> bool {
+        self.tables.len() == other.tables.len()
+            && self.tables.iter().zip(&other.tables).all(|(table, other_table)| table.table == other_table.table)
+    }
+
+    /// Returns an iterator over the linear combinations of the constraints and lookup constraints.
+    fn linear_combinations(&self) -> impl Iterator<Item = &AssignmentLC<F>> {
+        self.constraints
+            .iter()
+            .flat_map(|(a, b, c)| [a, b, c])
+            .chain(self.lookup_constraints.iter().flat_map(|(a, b, c, _)| [a, b, c]))
+    }
+}
+
+impl<F: Field> snarkvm_algorithms::r1cs::ConstraintSynthesizer<F> for Assignment<F> {
+    /// Synthesizes the constraints from the environment into a `snarkvm_algorithms::r1cs`-compliant constraint system.
+    ///
+    /// With the `parallel` feature, the linear combinations are converted in parallel, and enforced serially.
+    /// On the `generate_constraints_large` benchmark (262144 constraints) with 1 core, this took 632 ms against
+    /// 582 ms serially, i.e. no speedup. It has not been measured with more cores, so do not enable `parallel`
+    /// for this conversion alone, expecting it to be faster.
+    fn generate_constraints<CS: snarkvm_algorithms::r1cs::ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+    ) -> Result<(), snarkvm_algorithms::r1cs::SynthesisError> {
+        self.generate_constraints_with_progress(cs, |_, _| {})
+    }
+}
+
+/// The number of constraints that are enforced between consecutive calls to the progress callback.
+const PROGRESS_INTERVAL: usize = 1 << 16;
+
+/// The number of constraints that are converted in parallel at once, before being enforced serially.
+/// This bounds the number of converted linear combinations that are held at once, as their buffers are reused.
+#[cfg(feature = "parallel")]
+const PARALLEL_CHUNK_SIZE: usize = 1 << 12;
+
+/// The functions that name the public variables, private variables, and constraints during synthesis,
+/// given their indices.
+pub(super) struct SynthesisNames<'a> {
+    public: &'a dyn Fn(Index) -> String,
+    private: &'a dyn Fn(Index) -> String,
+    constraint: &'a dyn Fn(Index) -> String,
+}
+
+impl Default for SynthesisNames<'_> {
+    /// Returns the default names, `Public {i}`, `Private {i}`, and `Constraint {i}`.
+    fn default() -> Self {
+        fn public(i: Index) -> String {
+            format!("Public {i}")
+        }
+        fn private(i: Index) -> String {
+            format!("Private {i}")
+        }
+        fn constraint(i: Index) -> String {
+            format!("Constraint {i}")
+        }
+        Self { public: &public, private: &private, constraint: &constraint }
+    }
+}
+
+/// The position in the synthesis of an assignment, as reported to the progress callback
+/// (see [`Assignment::generate_constraints_with_progress`]), and from which an interrupted synthesis
+/// can resume (see [`Assignment::generate_constraints_from`]).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SynthesisCursor {
+    /// The number of enforced constraints, followed by the enforced lookup constraints.
+    num_enforced: usize,
+    /// Whether the lookup tables were added.
+    tables_added: bool,
+}
+
+impl SynthesisCursor {
+    /// Initializes a cursor after the given number of enforced constraints, which count the constraints,
+    /// followed by the lookup constraints, and with or without the lookup tables added.
+    pub const fn new(num_enforced: usize, tables_added: bool) -> Self {
+        Self { num_enforced, tables_added }
+    }
+
+    /// Returns the number of enforced constraints, followed by the enforced lookup constraints.
+    pub const fn num_enforced(&self) -> usize {
+        self.num_enforced
+    }
+
+    /// Returns `true` if the lookup tables were added.
+    pub const fn tables_added(&self) -> bool {
+        self.tables_added
+    }
+}
+
+/// The values of the variables to synthesize the constraints of an assignment with,
+/// and the mapping of the variables to the second system, if it was prepared ahead of synthesis.
+pub(super) struct SynthesisVariables<'a, F: Field> {
+    public: &'a IndexMap<Index, F>,
+    private: &'a IndexMap<Index, F>,
+    converter: Option<&'a PreparedConverter>,
+}
+
+impl<'a, F: Field> SynthesisVariables<'a, F> {
+    /// Returns the variables of the given assignment, without a prepared converter.
+    pub(super) fn of(assignment: &'a Assignment<F>) -> Self {
+        Self { public: &assignment.public, private: &assignment.private, converter: None }
+    }
+}
+
+impl<F: Field> Assignment<F> {
+    /// Synthesizes the constraints from the environment into a `snarkvm_algorithms::r1cs`-compliant constraint system,
+    /// calling `progress(cursor, num_constraints)` after every `PROGRESS_INTERVAL` enforced constraints,
+    /// and once all of the constraints (including the lookup constraints) are enforced.
+    /// The `cursor` is the position of the synthesis, from which it can resume if interrupted
+    /// (see [`Assignment::generate_constraints_from`]).
+    pub fn generate_constraints_with_progress<CS, P>(
+        &self,
+        cs: &mut CS,
+        progress: P,
+    ) -> Result<(), snarkvm_algorithms::r1cs::SynthesisError>
+    where
+        CS: snarkvm_algorithms::r1cs::ConstraintSystem<F>,
+        P: FnMut(SynthesisCursor, usize),
+    {
+        self.synthesize(
+            cs,
+            SynthesisVariables::of(self),
+            true,
+            &SynthesisNames::default(),
+            Default::default(),
+            progress,
+        )
+    }
+
+    /// Resumes synthesizing the constraints from the environment into a `snarkvm_algorithms::r1cs`-compliant
+    /// constraint system, from the given `cursor`, calling `progress` as in
+    /// [`Assignment::generate_constraints_with_progress`].
+    ///
+    /// The cursor is the last one reported to `progress` by the interrupted synthesis. To resume, `cs` must be
+    /// the constraint system of the interrupted synthesis, i.e. with all of the variables of the assignment
+    /// allocated, and exactly the first `cursor.num_enforced()` constraints enforced, counting the constraints,
+    /// followed by the lookup constraints. As the variables are allocated in index order, the mapping of the
+    /// variables is recovered from the assignment, without being allocated again.
+    ///
+    /// The lookup tables are added once all of the constraints are enforced, before the first lookup constraint,
+    /// and are only added again if `cursor.tables_added()` is `false`. Resuming from a lookup constraint requires
+    /// the lookup tables to be added. The default cursor is the same as a full synthesis.
+    pub fn generate_constraints_from<CS, P>(
+        &self,
+        cs: &mut CS,
+        cursor: SynthesisCursor,
+        progress: P,
+    ) -> Result<(), snarkvm_algorithms::r1cs::SynthesisError>
+    where
+        CS: snarkvm_algorithms::r1cs::ConstraintSystem<F>,
+        P: FnMut(SynthesisCursor, usize),
+    {
+        self.synthesize(cs, SynthesisVariables::of(self), true, &SynthesisNames::default(), cursor, progress)
+    }
+
+    /// Synthesizes the constraints from the environment into a `snarkvm_algorithms::r1cs`-compliant constraint system,
+    /// naming the public variables, private variables, and constraints with the given functions of their indices.
+    /// This is useful to label the diagnostics of the constraint system (e.g. `TestConstraintSystem::which_is_unsatisfied`),
+    /// which are otherwise named `Public {i}`, `Private {i}`, and `Constraint {i}`.
+    /// The lookup constraints are named `Lookup Constraint {i}`.
+    pub fn generate_constraints_named<CS, P, Q, C>(
+        &self,
+        cs: &mut CS,
+        public_name: P,
+        private_name: Q,
+        constraint_name: C,
+    ) -> Result<(), snarkvm_algorithms::r1cs::SynthesisError>
+    where
+        CS: snarkvm_algorithms::r1cs::ConstraintSystem<F>,
+        P: Fn(Index) -> String,
+        Q: Fn(Index) -> String,
+        C: Fn(Index) -> String,
+    {
+        let names = SynthesisNames { public: &public_name, private: &private_name, constraint: &constraint_name };
+        self.synthesize(cs, SynthesisVariables::of(self), true, &names, Default::default(), |_, _| {})
+    }
+
+    /// Synthesizes the constraints from the environment into a `snarkvm_algorithms::r1cs`-compliant constraint system,
+    /// with the values of the given `variables`, which must have the same shape as the assignment.
+    /// The lookup tables and lookup constraints are only synthesized if `with_lookups` is `true`.
+    /// The variables and constraints are named with the given `names`, and the `progress` callback is called
+    /// as in [`Assignment::generate_constraints_with_progress`]. If the `cursor` is not at the start, the synthesis
+    /// resumes in the given `cs` as in [`Assignment::generate_constraints_from`].
+    pub(super) fn synthesize<CS: snarkvm_algorithms::r1cs::ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+        variables: SynthesisVariables<F>,
+        with_lookups: bool,
+        names: &SynthesisNames,
+        cursor: SynthesisCursor,
+        mut progress: impl FnMut(SynthesisCursor, usize),
+    ) -> Result<(), snarkvm_algorithms::r1cs::SynthesisError> {
+        // Track the mapping of variables from the virtual machine (first) to the gadget constraint system (second),
+        // unless it was prepared ahead of synthesis.
+        let mut allocated = PreparedConverter { public: Default::default(), private: Default::default() };
+        let (num_public, num_private) = (variables.public.len() as u64, variables.private.len() as u64);
+
+        /// Returns an `AssignmentMismatch` error with the given message.
+        macro_rules! mismatch {
+            ($($message:tt)+) => {
+                snarkvm_algorithms::r1cs::SynthesisError::AssignmentMismatch(format!($($message)+))
+            };
+        }
+
+        /// Returns an `AssignmentMismatch` error with the given message, if the condition does not hold.
+        macro_rules! ensure_match {
+            ($condition:expr, $($message:tt)+) => {
+                if !$condition {
+                    return Err(mismatch!($($message)+));
+                }
+            };
+        }
+
+        // Ensure the lookup constraints reference existing tables.
+        if with_lookups {
+            self.validate_lookup_indices()?;
+        }
+
+        // Ensure the prepared converter matches the shape of the variables.
+        if let Some(converter) = variables.converter {
+            ensure_match!(
+                converter.num_public() == num_public && converter.num_private() == num_private,
+                "The prepared converter must match the number of public and private variables in the first system"
+            );
+        }
+
+        // Determine the total number of constraints, which is the end of the cursor.
+        let total = match with_lookups {
+            true => self.constraints.len() + self.lookup_constraints.len(),
+            false => self.constraints.len(),
+        };
+        let start_index = cursor.num_enforced;
+        ensure_match!(start_index <= total, "Cannot resume from constraint {start_index}, as there are only {total}");
+        ensure_match!(
+            !with_lookups || start_index <= self.constraints.len() || cursor.tables_added,
+            "Cannot resume from lookup constraint {}, as the lookup tables were not added",
+            start_index - self.constraints.len()
+        );
+
+        // Ensure the given `cs` is starting off clean, or where the interrupted synthesis left off.
+        let resume = start_index > 0 || cursor.tables_added;
+        match resume {
+            false => {
+                ensure_match!(
+                    cs.num_public_variables() == 1,
+                    "The constraint system must start with only the one variable"
+                );
+                ensure_match!(
+                    cs.num_private_variables() == 0,
+                    "The constraint system must start without private variables"
+                );
+                ensure_match!(cs.num_constraints() == 0, "The constraint system must start without constraints");
+            }
+            true => {
+                ensure_match!(
+                    num_public + 1 == cs.num_public_variables() as u64,
+                    "The constraint system must resume with all {} public variables, found {}",
+                    num_public + 1,
+                    cs.num_public_variables()
+                );
+                ensure_match!(
+                    num_private == cs.num_private_variables() as u64,
+                    "The constraint system must resume with all {} private variables, found {}",
+                    num_private,
+                    cs.num_private_variables()
+                );
+                ensure_match!(
+                    start_index == cs.num_constraints(),
+                    "The constraint system must resume with {start_index} constraints, found {}",
+                    cs.num_constraints()
+                );
+            }
+        }
+
+        // Allocate the public variables, or recover them if resuming.
+        for (i, (index, value)) in variables.public.iter().enumerate() {
+            ensure_match!(
+                i as u64 == *index,
+                "Public variables in first system must be processed in lexicographic order (expected {i}, found {index})"
+            );
+
+            let gadget = match resume {
+                false => cs.alloc_input(|| (names.public)(*index), || Ok(*value))?,
+                true => snarkvm_algorithms::r1cs::Variable::new_unchecked(snarkvm_algorithms::r1cs::Index::Public(
+                    to_usize_index(*index, 1)?,
+                )),
+            };
+
+            ensure_match!(
+                snarkvm_algorithms::r1cs::Index::Public(to_usize_index(*index, 1)?) == gadget.get_unchecked(),
+                "Public variables in the second system must match the first system (with an off-by-1 for the public case)"
+            );
+
+            match variables.converter {
+                Some(converter) => ensure_match!(
+                    converter.public.get(index) == Some(&gadget),
+                    "Public variable {index} in the second system must match the prepared converter"
+                ),
+                None => {
+                    let result = allocated.public.insert(*index, gadget);
+                    ensure_match!(result.is_none(), "Overwrote an existing public variable in the converter");
+                }
+            }
+        }
+
+        // Allocate the private variables, or recover them if resuming.
+        for (i, (index, value)) in variables.private.iter().enumerate() {
+            ensure_match!(
+                i as u64 == *index,
+                "Private variables in first system must be processed in lexicographic order (expected {i}, found {index})"
+            );
+
+            let gadget = match resume {
+                false => cs.alloc(|| (names.private)(*index), || Ok(*value))?,
+                true => snarkvm_algorithms::r1cs::Variable::new_unchecked(snarkvm_algorithms::r1cs::Index::Private(i)),
+            };
+
+            ensure_match!(
+                snarkvm_algorithms::r1cs::Index::Private(i) == gadget.get_unchecked(),
+                "Private variables in the second system must match the first system"
+            );
+
+            match variables.converter {
+                Some(converter) => ensure_match!(
+                    converter.private.get(index) == Some(&gadget),
+                    "Private variable {index} in the second system must match the prepared converter"
+                ),
+                None => {
+                    let result = allocated.private.insert(*index, gadget);
+                    ensure_match!(result.is_none(), "Overwrote an existing private variable in the converter");
+                }
+            }
+        }
+
+        let converter = variables.converter.unwrap_or(&allocated);
+
+        // Converts terms from one linear combination in the first system to the second system.
+        // The given linear combination of the second system is overwritten, reusing its allocation.
+        let convert_linear_combination = |lc: &AssignmentLC<F>,
+                                          linear_combination: &mut snarkvm_algorithms::r1cs::LinearCombination<F>|
+         -> Result<(), snarkvm_algorithms::r1cs::SynthesisError> {
+            // Clear the linear combination of the second system.
+            linear_combination.0.clear();
+
+            // Process every term in the linear combination.
+            for (variable, coefficient) in lc.terms.iter() {
+                match variable {
+                    AssignmentVariable::Constant(_) => {
+                        return Err(mismatch!(
+                            "Failed during constraint translation. The first system by definition cannot have constant variables in the terms"
+                        ));
+                    }
+                    AssignmentVariable::Public(index) => {
+                        let gadget = converter.public.get(index).ok_or_else(|| {
+                            mismatch!("Failed during constraint translation. The public variable {index} is not in the first system")
+                        })?;
+                        ensure_match!(
+                            snarkvm_algorithms::r1cs::Index::Public(to_usize_index(*index, 1)?) == gadget.get_unchecked(),
+                            "Failed during constraint translation. The public variable in the second system must match the first system (with an off-by-1 for the public case)"
+                        );
+                        *linear_combination += (*coefficient, *gadget);
+                    }
+                    AssignmentVariable::Private(index) => {
+                        let gadget = converter.private.get(index).ok_or_else(|| {
+                            mismatch!("Failed during constraint translation. The private variable {index} is not in the first system")
+                        })?;
+                        ensure_match!(
+                            snarkvm_algorithms::r1cs::Index::Private(to_usize_index(*index, 0)?) == gadget.get_unchecked(),
+                            "Failed during constraint translation. The private variable in the second system must match the first system"
+                        );
+                        *linear_combination += (*coefficient, *gadget);
+                    }
+                }
+            }
+
+            // Finally, add the accumulated constant value to the linear combination.
+            if !lc.constant.is_zero() {
+                *linear_combination += (
+                    lc.constant,
+                    snarkvm_algorithms::r1cs::Variable::new_unchecked(snarkvm_algorithms::r1cs::Index::Public(0)),
+                );
+            }
+
+            Ok(())
+        };
+
+        // Reports the progress at every interval, and once all of the constraints are enforced.
+        // The final report is held back until the lookup tables are added, so that resuming from it is a no-op.
+        let mut next_report = (start_index / PROGRESS_INTERVAL + 1).saturating_mul(PROGRESS_INTERVAL).min(total);
+        let mut report_progress = |num_enforced: usize, tables_added: bool| {
+            if num_enforced == next_report && (num_enforced < total || tables_added || !with_lookups) {
+                progress(SynthesisCursor { num_enforced, tables_added }, total);
+                next_report = next_report.saturating_add(PROGRESS_INTERVAL).min(total);
+            }
+        };
+
+        // Initialize the linear combinations of the second system, which are reused across the constraints.
+        let mut a_lc = snarkvm_algorithms::r1cs::LinearCombination::<F>::zero();
+        let mut b_lc = snarkvm_algorithms::r1cs::LinearCombination::<F>::zero();
+        let mut c_lc = snarkvm_algorithms::r1cs::LinearCombination::<F>::zero();
+
+        // Determine the first constraint and the first lookup constraint to enforce.
+        let constraint_start = start_index.min(self.constraints.len());
+        let lookup_start = start_index - constraint_start;
+
+        // Enforce all of the constraints.
+        #[cfg(not(feature = "parallel"))]
+        for (i, (a, b, c)) in self.constraints.iter().enumerate().skip(constraint_start) {
+            convert_linear_combination(a, &mut a_lc)?;
+            convert_linear_combination(b, &mut b_lc)?;
+            convert_linear_combination(c, &mut c_lc)?;
+            cs.enforce(|| (names.constraint)(i as Index), |lc| lc + &a_lc, |lc| lc + &b_lc, |lc| lc + &c_lc);
+            report_progress(i + 1, cursor.tables_added);
+        }
+
+        // Enforce all of the constraints, converting them in parallel, as `cs` can only be written to serially.
+        // The constraints are converted in chunks, into buffers that are reused across the chunks,
+        // and the first error of a chunk is taken in order, so it is the same as in the serial case.
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+
+            let mut buffers = Vec::with_capacity(PARALLEL_CHUNK_SIZE);
+            for (j, chunk) in self.constraints[constraint_start..].chunks(PARALLEL_CHUNK_SIZE).enumerate() {
+                buffers.resize_with(chunk.len(), || {
+                    (
+                        snarkvm_algorithms::r1cs::LinearCombination::<F>::zero(),
+                        snarkvm_algorithms::r1cs::LinearCombination::<F>::zero(),
+                        snarkvm_algorithms::r1cs::LinearCombination::<F>::zero(),
+                    )
+                });
+                let error = buffers.par_iter_mut().zip(chunk).find_map_first(|((a_lc, b_lc, c_lc), (a, b, c))| {
+                    convert_linear_combination(a, a_lc)
+                        .and_then(|_| convert_linear_combination(b, b_lc))
+                        .and_then(|_| convert_linear_combination(c, c_lc))
+                        .err()
+                });
+                if let Some(error) = error {
+                    return Err(error);
+                }
+                for (i, (a_lc, b_lc, c_lc)) in buffers.iter().enumerate() {
+                    let i = constraint_start + j * PARALLEL_CHUNK_SIZE + i;
+                    cs.enforce(|| (names.constraint)(i as Index), |lc| lc + a_lc, |lc| lc + b_lc, |lc| lc + c_lc);
+                    report_progress(i + 1, cursor.tables_added);
+                }
+            }
+        }
+
+        if with_lookups {
+            // Add the lookup tables, unless they were added before resuming.
+            if !cursor.tables_added {
+                for table in &self.tables {
+                    cs.add_lookup_table(table.clone())
+                }
+                report_progress(self.constraints.len(), true);
+            }
+
+            // Enforce all of the lookup constraints.
+            for (i, (a, b, c, table_index)) in self.lookup_constraints.iter().enumerate().skip(lookup_start) {
+                convert_linear_combination(a, &mut a_lc)?;
+                convert_linear_combination(b, &mut b_lc)?;
+                convert_linear_combination(c, &mut c_lc)?;
+                cs.enforce_lookup(
+                    || format!("Lookup Constraint {i}"),
+                    |lc| lc + &a_lc,
+                    |lc| lc + &b_lc,
+                    |lc| lc + &c_lc,
+                    *table_index,
+                )?;
+                report_progress(self.constraints.len() + i + 1, true);
+            }
+        }
+
+        // Ensure the given `cs` matches in size with the first system.
+        ensure_match!(
+            num_public + 1 == cs.num_public_variables() as u64,
+            "Expected {} public variables in the second system, found {}",
+            num_public + 1,
+            cs.num_public_variables()
+        );
+        ensure_match!(
+            num_private == cs.num_private_variables() as u64,
+            "Expected {} private variables in the second system, found {}",
+            num_private,
+            cs.num_private_variables()
+        );
+        let num_constraints = match with_lookups {
+            true => self.num_constraints() + self.num_lookup_constraints(),
+            false => self.num_constraints(),
+        };
+        ensure_match!(
+            num_constraints == cs.num_constraints() as u64,
+            "Expected {} constraints in the second system, found {}",
+            num_constraints,
+            cs.num_constraints()
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use snarkvm_algorithms::{r1cs::ConstraintSynthesizer, AlgebraicSponge, SNARK};
+    use snarkvm_circuit::prelude::*;
+    use snarkvm_curves::bls12_377::Fr;
+
+    /// Compute 2^EXPONENT - 1, in a purposefully constraint-inefficient manner for testing.
+    pub(super) fn create_example_circuit<E: Environment>() -> Field<E> {
+        let one = snarkvm_console_types::Field::<E::Network>::one();
+        let two = one + one;
+
+        const EXPONENT: u64 = 64;
+
+        // Compute 2^EXPONENT - 1, in a purposefully constraint-inefficient manner for testing.
+        let mut candidate = Field::<E>::new(Mode::Public, one);
+        let mut accumulator = Field::new(Mode::Private, two);
+        for _ in 0..EXPONENT {
+            candidate += &accumulator;
+            accumulator *= Field::new(Mode::Private, two);
+        }
+
+        assert_eq!((accumulator - Field::one()).eject_value(), candidate.eject_value());
+        assert_eq!(2, E::num_public());
+        assert_eq!(2 * EXPONENT + 1, E::num_private());
+        assert_eq!(EXPONENT, E::num_constraints());
+        assert!(E::is_satisfied());
+
+        candidate
+    }
+
+    /// Returns a handcrafted assignment for `x^3 + x + 5 == 35`, with `x` private and the output public.
+    pub(crate) fn create_example_assignment<F: snarkvm_fields::Field>() -> super::Assignment<F> {
+        use super::{AssignmentLC, AssignmentVariable::*};
+
+        let (zero, one) = (F::zero(), F::one());
+        let x = F::from(3u64);
+
+        let lc = |constant: F, terms: &[(super::AssignmentVariable<F>, F)]| AssignmentLC {
+            constant,
+            terms: terms.iter().cloned().collect(),
+        };
+
+        super::Assignment {
+            public: [(0, one), (1, F::from(35u64))].into_iter().collect(),
+            private: [(0, x), (1, x * x), (2, x * x * x)].into_iter().collect(),
+            constraints: vec![
+                (lc(zero, &[(Private(0), one)]), lc(zero, &[(Private(0), one)]), lc(zero, &[(Private(1), one)])),
+                (lc(zero, &[(Private(1), one)]), lc(zero, &[(Private(0), one)]), lc(zero, &[(Private(2), one)])),
+                (
+                    lc(F::from(5u64), &[(Private(2), one), (Private(0), one)]),
+                    lc(one, &[]),
+                    lc(zero, &[(Public(1), one)]),
+                ),
+            ],
+            tables: vec![],
+            lookup_constraints: vec![],
+            circuit_version: None,
+        }
+    }
+
+    /// Returns the linear combination of the given variable, with a coefficient of one.
+    pub(crate) fn lc<F: snarkvm_fields::Field>(variable: super::AssignmentVariable<F>) -> super::AssignmentLC<F> {
+        super::AssignmentLC { constant: F::zero(), terms: [(variable, F::one())].into_iter().collect() }
+    }
+
+    /// Returns a lookup table computing `x * x^2` for the `x = 3` of the example assignment.
+    pub(crate) fn create_example_table<F: snarkvm_fields::Field>() -> super::LookupTable<F> {
+        let mut table = super::LookupTable::default();
+        table.fill([F::from(3u64), F::from(9u64)], F::from(27u64));
+        table
+    }
+
+    /// Returns the example assignment, with the example table, looked up once as `(x, x^2) -> x^3`.
+    pub(crate) fn create_example_assignment_with_lookup<F: snarkvm_fields::Field>() -> super::Assignment<F> {
+        use super::AssignmentVariable::Private;
+
+        let mut assignment = create_example_assignment::<F>();
+        assignment.tables.push(create_example_table());
+        assignment.lookup_constraints.push((lc(Private(0)), lc(Private(1)), lc(Private(2)), 0));
+        assignment
+    }
+
+    /// Synthesizes both assignments into separate test constraint systems, and asserts that the systems have
+    /// the same number of public variables, private variables, and constraints, and agree on satisfiability.
+    pub(crate) fn assert_systems_equivalent<F: snarkvm_fields::Field>(
+        a: &super::Assignment<F>,
+        b: &super::Assignment<F>,
+    ) {
+        use snarkvm_algorithms::r1cs::{ConstraintSystem, TestConstraintSystem};
+
+        let (mut a_cs, mut b_cs) = (TestConstraintSystem::new(), TestConstraintSystem::new());
+        a.generate_constraints(&mut a_cs).unwrap();
+        b.generate_constraints(&mut b_cs).unwrap();
+        assert_eq!(a_cs.num_public_variables(), b_cs.num_public_variables(), "Mismatched public variables");
+        assert_eq!(a_cs.num_private_variables(), b_cs.num_private_variables(), "Mismatched private variables");
+        assert_eq!(a_cs.num_constraints(), b_cs.num_constraints(), "Mismatched constraints");
+        assert_eq!(a_cs.is_satisfied(), b_cs.is_satisfied(), "Mismatched satisfiability");
+    }
+
+    #[test]
+    fn test_constraint_converter() {
+        let _candidate_output = create_example_circuit::<Circuit>();
+        let assignment = Circuit::eject_assignment_and_reset();
+        assert_eq!(0, Circuit::num_constants());
+        assert_eq!(1, Circuit::num_public());
+        assert_eq!(0, Circuit::num_private());
+        assert_eq!(0, Circuit::num_constraints());
+
+        let mut cs = snarkvm_algorithms::r1cs::TestConstraintSystem::new();
+        assignment.generate_constraints(&mut cs).unwrap();
+        {
+            use snarkvm_algorithms::r1cs::ConstraintSystem;
+            assert_eq!(assignment.num_public() + 1, cs.num_public_variables() as u64);
+            assert_eq!(assignment.num_private(), cs.num_private_variables() as u64);
+            assert_eq!(assignment.num_constraints(), cs.num_constraints() as u64);
+            assert!(cs.is_satisfied());
+        }
+    }
+
+    #[test]
+    fn test_assignment_variable() {
+        use super::AssignmentVariable;
+
+        let constant = AssignmentVariable::Constant(Fr::one());
+        assert!(constant.is_constant() && !constant.is_public() && !constant.is_private());
+        assert_eq!(None, constant.index());
+
+        let public = AssignmentVariable::<Fr>::Public(1);
+        assert!(!public.is_constant() && public.is_public() && !public.is_private());
+        assert_eq!(Some(1), public.index());
+
+        let private = AssignmentVariable::<Fr>::Private(2);
+        assert!(!private.is_constant() && !private.is_public() && private.is_private());
+        assert_eq!(Some(2), private.index());
+    }
+
+    #[test]
+    fn test_assert_systems_equivalent() {
+        let assignment = create_example_assignment::<Fr>();
+        assert_systems_equivalent(&assignment, &assignment.clone().canonical_form());
+
+        // Ensure an unsatisfied assignment is not equivalent.
+        let mut other = assignment.clone();
+        other.private[1] += Fr::one();
+        let result = std::panic::catch_unwind(|| assert_systems_equivalent(&assignment, &other));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_is_constant() {
+        let assignment = create_example_assignment::<Fr>();
+
+        // Ensure only the linear combinations without terms are constant.
+        let (a, b, c) = &assignment.constraints[2];
+        assert!(!a.is_constant());
+        assert_eq!(None, a.as_constant());
+        assert!(b.is_constant());
+        assert_eq!(Some(b.constant()), b.as_constant());
+        assert!(!c.is_constant());
+        assert_eq!(None, c.as_constant());
+    }
+
+    #[test]
+    fn test_get_coefficient() {
+        use super::AssignmentVariable;
+
+        let assignment = create_example_assignment::<Fr>();
+
+        let (a, b, _) = &assignment.constraints[2];
+        assert!(a.contains(&AssignmentVariable::Private(2)));
+        assert_eq!(Fr::one(), a.get_coefficient(&AssignmentVariable::Private(2)));
+        assert!(!a.contains(&AssignmentVariable::Private(1)));
+        assert_eq!(Fr::zero(), a.get_coefficient(&AssignmentVariable::Private(1)));
+
+        // Ensure the constant term is not a coefficient.
+        assert!(!b.contains(&AssignmentVariable::Constant(Fr::one())));
+        assert_eq!(Fr::zero(), b.get_coefficient(&AssignmentVariable::Constant(Fr::one())));
+    }
+
+    #[test]
+    fn test_from_terms() {
+        use super::{AssignmentLC, AssignmentVariable::*};
+
+        let (one, two) = (Fr::one(), Fr::from(2u64));
+
+        // Ensure the coefficients of repeated variables are summed.
+        let lc = AssignmentLC::from_terms(one, [(Private(0), one), (Public(1), two), (Private(0), one)]);
+        assert_eq!(one, lc.constant());
+        assert_eq!(2, lc.terms().len());
+        assert_eq!(two, lc.get_coefficient(&Private(0)));
+        assert_eq!(two, lc.get_coefficient(&Public(1)));
+
+        // Ensure the variables with a zero coefficient are dropped.
+        let mut other = lc.clone();
+        other.extend([(Private(0), -two), (Private(1), Fr::zero())]);
+        assert_eq!(AssignmentLC::from_terms(one, [(Public(1), two)]), other);
+        assert!(AssignmentLC::from_terms(one, [(Private(0), one), (Private(0), -one)]).is_constant());
+
+        // Ensure the linear combination matches the one converted from the circuit.
+        let assignment = create_example_assignment::<Fr>();
+        let (a, _, _) = &assignment.constraints[2];
+        assert_eq!(*a, AssignmentLC::from_terms(Fr::from(5u64), [(Private(0), one), (Private(2), one)]));
+    }
+
+    #[test]
+    fn test_num_terms() {
+        let assignment = create_example_assignment::<Fr>();
+
+        // Ensure the constant is only counted as a nonzero.
+        let (a, b, c) = &assignment.constraints[2];
+        assert_eq!((2, 3), (a.num_terms(), a.num_nonzeros()));
+        assert_eq!((0, 1), (b.num_terms(), b.num_nonzeros()));
+        assert_eq!((1, 1), (c.num_terms(), c.num_nonzeros()));
+    }
+
+    #[test]
+    fn test_num_nonzeros_breakdown() {
+        let assignment = create_example_assignment::<Fr>();
+
+        // Ensure the breakdown lists each constraint in order, and folds into the total.
+        assert_eq!(vec![(1, 1, 1), (1, 1, 1), (3, 1, 1)], assignment.num_nonzeros_breakdown());
+        assert_eq!((5, 3, 3), assignment.num_nonzeros());
+    }
+
+    #[test]
+    fn test_rayon_num_nonzeros() {
+        let mut assignment = create_example_assignment::<Fr>();
+        assert_eq!((5, 3, 3), assignment.rayon_num_nonzeros());
+
+        // Ensure the count matches the serial count for many constraints and lookup constraints.
+        let constraints = assignment.constraints.clone();
+        for _ in 0..1000 {
+            assignment.constraints.extend(constraints.iter().cloned());
+            let (a, b, c) = constraints[2].clone();
+            assignment.lookup_constraints.push((a, b, c, 0));
+        }
+        assert_eq!((5 * 1001 + 3000, 3 * 1001 + 1000, 3 * 1001 + 1000), assignment.num_nonzeros());
+        assert_eq!(assignment.num_nonzeros(), assignment.rayon_num_nonzeros());
+    }
+
+    #[test]
+    fn test_eq() {
+        use snarkvm_fields::One;
+
+        let assignment = create_example_assignment::<Fr>();
+
+        // Ensure reordering the terms and the variables preserves equality.
+        let mut other = assignment.clone();
+        other.constraints[2].0.terms.reverse();
+        other.private.reverse();
+        assert_eq!(assignment, other);
+
+        // Ensure changing a coefficient, a value, or the circuit version breaks equality.
+        let mut other = assignment.clone();
+        other.constraints[2].0.terms[0] += Fr::one();
+        assert_ne!(assignment, other);
+        let mut other = assignment.clone();
+        other.public[1] += Fr::one();
+        assert_ne!(assignment, other);
+        assert_ne!(assignment, assignment.clone().with_circuit_version(1));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_create_with_bases() {
+        use snarkvm_fields::One;
+
+        let base = std::sync::Arc::new(create_example_assignment::<Fr>());
+        let mut witness = create_example_assignment::<Fr>();
+        witness.private.values_mut().for_each(|value| *value += Fr::one());
+
+        // Ensure every assignment shares the base.
+        let assignments =
+            super::SameCircuitAssignment::create_with_bases(base.clone(), vec![witness.clone(); 3]).unwrap();
+        assert_eq!(3, assignments.len());
+        assert_eq!(4, std::sync::Arc::strong_count(&base));
+        assert!(assignments.iter().all(|assignment| assignment.private_inputs() == &witness.private));
+
+        // Ensure a mismatched witness is rejected, identifying its index.
+        let mut mismatched = witness.clone();
+        mismatched.private.pop();
+        let error = super::SameCircuitAssignment::create_with_bases(base.clone(), vec![witness.clone(), mismatched])
+            .unwrap_err();
+        assert_eq!("Assignment mismatch: Witness 1: Expected 3 private variables, found 2", error.to_string());
+
+        // Ensure a witness with a different number of constraints is rejected, as by `create_with_base`.
+        let mut mismatched = witness.clone();
+        mismatched.constraints.pop();
+        assert!(super::SameCircuitAssignment::create_with_base(base.clone(), mismatched.clone()).is_err());
+        let error = super::SameCircuitAssignment::create_with_bases(base, vec![mismatched, witness]).unwrap_err();
+        assert_eq!("Assignment mismatch: Witness 0: Expected 3 constraints, found 2", error.to_string());
+    }
+
+    #[test]
+    fn test_with_public_inputs() {
+        let assignment = create_example_assignment::<Fr>();
+
+        // Ensure the public variables are substituted.
+        let new_public: IndexMap<_, _> = [(0, Fr::from(1u64)), (1, Fr::from(36u64))].into_iter().collect();
+        let candidate = assignment.with_public_inputs(new_public.clone()).unwrap();
+        assert_eq!(&new_public, candidate.public_inputs());
+        assert_eq!(assignment.private_inputs(), candidate.private_inputs());
+        assert!(!candidate.is_satisfied());
+
+        // Ensure mismatched indices are rejected, naming the first index that does not line up.
+        let new_public: IndexMap<_, _> = [(0, Fr::from(1u64)), (2, Fr::from(35u64))].into_iter().collect();
+        let error = assignment.with_public_inputs(new_public).unwrap_err();
+        assert_eq!(
+            "Assignment mismatch: Public variable 1 has index Some(2), but the base has index Some(1)",
+            error.to_string()
+        );
+        let new_public: IndexMap<_, _> = [(0, Fr::from(1u64))].into_iter().collect();
+        assert!(assignment.with_public_inputs(new_public).is_err());
+    }
+
+    #[test]
+    fn test_clone_structure_with_values() {
+        let assignment = create_example_assignment::<Fr>();
+
+        // Substitute the witness for `x = 2`, such that `x^3 + x + 5 == 15`.
+        let public = [Fr::one(), Fr::from(15u64)];
+        let private = [Fr::from(2u64), Fr::from(4u64), Fr::from(8u64)];
+        let candidate = assignment.clone_structure_with_values(&public, &private).unwrap();
+        assert_eq!((public.to_vec(), private.to_vec()), candidate.split_public());
+        assert_eq!(assignment.constraints(), candidate.constraints());
+        assert!(candidate.is_satisfied());
+
+        // Ensure the number of values must match.
+        let error = assignment.clone_structure_with_values(&public[..1], &private).unwrap_err();
+        assert_eq!("Assignment mismatch: Expected 2 public values, found 1", error.to_string());
+        let error =
+            assignment.clone_structure_with_values(&public, &[private.as_slice(), &[Fr::one()]].concat()).unwrap_err();
+        assert_eq!("Assignment mismatch: Expected 3 private values, found 4", error.to_string());
+    }
+
+    #[test]
+    fn test_public_input_values() {
+        let mut assignment = create_example_assignment::<Fr>();
+        assert_eq!(vec![Fr::one(), Fr::from(35u64)], assignment.public_input_values());
+
+        // Ensure the values are in index order.
+        assignment.public.reverse();
+        assert_eq!(vec![Fr::one(), Fr::from(35u64)], assignment.public_input_values());
+    }
+
+    #[test]
+    fn test_split_public() {
+        let mut assignment = create_example_assignment::<Fr>();
+        let x = Fr::from(3u64);
+        let expected = (vec![Fr::one(), Fr::from(35u64)], vec![x, x * x, x * x * x]);
+        assert_eq!(expected, assignment.split_public());
+
+        // Ensure the values match the variables allocated by the synthesizer.
+        let mut cs = snarkvm_algorithms::r1cs::TestConstraintSystem::new();
+        assignment.generate_constraints(&mut cs).unwrap();
+        for (i, value) in expected.0.iter().enumerate() {
+            assert_eq!(*value, cs.get(&format!("Public {i}")));
+        }
+        for (i, value) in expected.1.iter().enumerate() {
+            assert_eq!(*value, cs.get(&format!("Private {i}")));
+        }
+
+        // Ensure the values are in index order.
+        assignment.public.reverse();
+        assignment.private.reverse();
+        assert_eq!(expected, assignment.split_public());
+    }
+
+    #[test]
+    fn test_lookup_tables() {
+        let mut assignment = create_example_assignment::<Fr>();
+        assert!(assignment.lookup_tables().is_empty());
+        assert!(assignment.lookup_table(0).is_none());
+
+        let table = create_example_table::<Fr>();
+        assignment.tables.push(table.clone());
+        assert_eq!(1, assignment.lookup_tables().len());
+        assert_eq!(Some(&table.table), assignment.lookup_table(0).map(|table| &table.table));
+        assert!(assignment.lookup_table(1).is_none());
+    }
+
+    #[test]
+    fn test_lookup_constraints() {
+        let mut assignment = create_example_assignment::<Fr>();
+        assert!(assignment.lookup_constraints().is_empty());
+        assert!(assignment.lookup_constraint(0).is_none());
+
+        let (a, b, c) = assignment.constraints()[1].clone();
+        assignment.lookup_constraints.push((a.clone(), b.clone(), c.clone(), 0));
+        assert_eq!(1, assignment.lookup_constraints().len());
+        assert_eq!(Some(&(a, b, c, 0)), assignment.lookup_constraint(0));
+        assert!(assignment.lookup_constraint(1).is_none());
+    }
+
+    #[test]
+    fn test_variables() {
+        let assignment = create_example_assignment::<Fr>();
+
+        let candidate = assignment.variables().collect::<Vec<_>>();
+        assert_eq!(assignment.num_public() + assignment.num_private(), candidate.len() as u64);
+        assert_eq!((super::AssignmentVariable::Public(0), Fr::one()), candidate[0]);
+        assert_eq!((super::AssignmentVariable::Public(1), Fr::from(35u64)), candidate[1]);
+        assert_eq!((super::AssignmentVariable::Private(0), Fr::from(3u64)), candidate[2]);
+        assert_eq!((super::AssignmentVariable::Private(2), Fr::from(27u64)), candidate[4]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_base_and_witness() {
+        let base = create_example_assignment::<Fr>();
+        let mut witness = base.clone();
+        witness.private[0] = Fr::from(4u64);
+
+        let candidate = super::SameCircuitAssignment::from_base_and_witness(base.clone(), witness.clone()).unwrap();
+        assert_eq!(witness.public_inputs(), candidate.public_inputs());
+        assert_eq!(witness.private_inputs(), candidate.private_inputs());
+
+        let expected =
+            super::SameCircuitAssignment::create_with_base(base.clone().to_same_circuit_base(), witness).unwrap();
+        assert_eq!(format!("{expected:?}"), format!("{candidate:?}"));
+
+        // Ensure a witness of a different shape is rejected.
+        let mut witness = base.clone();
+        witness.private.pop();
+        assert!(super::SameCircuitAssignment::from_base_and_witness(base.clone(), witness).is_err());
+        let mut witness = base.clone();
+        witness.constraints.pop();
+        assert!(super::SameCircuitAssignment::from_base_and_witness(base, witness).is_err());
+        assert_eq!(format!("{expected:?}"), format!("{candidate:?}"));
+    }
+
+    #[test]
+    fn test_generate_constraints_first_error() {
+        use snarkvm_algorithms::r1cs::SynthesisError;
+        use snarkvm_fields::One;
+
+        // Repeat the constraints past several chunks of the parallel conversion, and synthesize them.
+        let mut assignment = create_example_assignment::<Fr>();
+        let constraints = assignment.constraints.clone();
+        assignment.constraints = constraints.iter().cycle().take(10_000).cloned().collect();
+        let mut cs = super::RecordingConstraintSystem::new();
+        assignment.generate_constraints(&mut cs).unwrap();
+        assert_eq!(&assignment, cs.assignment());
+
+        // Ensure the first error in order is returned, with or without the parallel conversion.
+        for (i, index) in [(9_000, 9), (5_001, 8), (5_000, 7)] {
+            assignment.constraints[i].1.terms.insert(super::AssignmentVariable::Private(index), Fr::one());
+        }
+        let mut cs = super::RecordingConstraintSystem::new();
+        match assignment.generate_constraints(&mut cs) {
+            Err(SynthesisError::AssignmentMismatch(message)) => assert!(message.contains("private variable 7 ")),
+            result => panic!("Expected an assignment mismatch, found {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_generate_constraints_with_progress() {
+        let mut assignment = create_example_assignment::<Fr>();
+
+        // Ensure the progress is reported once all of the constraints are enforced.
+        let mut calls = vec![];
+        let mut cs = snarkvm_algorithms::r1cs::TestConstraintSystem::new();
+        assignment.generate_constraints_with_progress(&mut cs, |cursor, total| calls.push((cursor, total))).unwrap();
+        assert_eq!(vec![(super::SynthesisCursor::new(3, true), 3)], calls);
+        assert!(cs.is_satisfied());
+
+        // Ensure the progress is reported at every interval.
+        let constraint = assignment.constraints[0].clone();
+        assignment.constraints.resize(super::PROGRESS_INTERVAL + 1, constraint);
+        let total = super::PROGRESS_INTERVAL + 1;
+        let mut calls = vec![];
+        let mut cs = snarkvm_algorithms::r1cs::TestConstraintSystem::new();
+        assignment.generate_constraints_with_progress(&mut cs, |cursor, total| calls.push((cursor, total))).unwrap();
+        // Ensure the lookup tables are only reported as added once they are, after all of the constraints.
+        let expected = [(super::PROGRESS_INTERVAL, false), (total, true)];
+        assert_eq!(
+            expected.map(|(done, tables_added)| (super::SynthesisCursor::new(done, tables_added), total)),
+            *calls
+        );
+    }
+
+    #[test]
+    fn test_generate_constraints_from() {
+        use snarkvm_algorithms::r1cs::SynthesisError;
+
+        let mut assignment = create_example_assignment_with_lookup::<Fr>();
+        assignment.lookup_constraints.push(assignment.lookup_constraints[0].clone());
+
+        let mut expected = super::RecordingConstraintSystem::new();
+        assignment.generate_constraints(&mut expected).unwrap();
+
+        // Ensure resuming from every cursor synthesizes the same constraint system as a full synthesis,
+        // including right after the lookup tables are added, before the first lookup constraint is enforced.
+        for (start_index, tables_added) in
+            [(0, false), (1, false), (2, false), (3, false), (3, true), (4, true), (5, true)]
+        {
+            // Synthesize the variables and the first `start_index` constraints, as an interrupted synthesis would.
+            let mut interrupted = assignment.clone();
+            interrupted.constraints.truncate(start_index);
+            interrupted.lookup_constraints.truncate(start_index.saturating_sub(3));
+            if !tables_added {
+                interrupted.tables.clear();
+            }
+            let mut cs = super::RecordingConstraintSystem::new();
+            if start_index > 0 || tables_added {
+                interrupted.generate_constraints(&mut cs).unwrap();
+            }
+
+            let mut calls = vec![];
+            let cursor = super::SynthesisCursor::new(start_index, tables_added);
+            assignment.generate_constraints_from(&mut cs, cursor, |cursor, total| calls.push((cursor, total))).unwrap();
+            assert_eq!(1, cs.assignment().num_lookup_tables());
+            assert_eq!(expected.assignment(), cs.assignment());
+            // Ensure the progress is reported once the remaining constraints are enforced, if any.
+            let end = super::SynthesisCursor::new(5, true);
+            assert_eq!(if start_index < 5 { vec![(end, 5)] } else { vec![] }, calls);
+        }
+
+        // Ensure a constraint system that does not match the cursor is rejected.
+        let mut cs = super::RecordingConstraintSystem::new();
+        let result = assignment.generate_constraints_from(&mut cs, super::SynthesisCursor::new(1, false), |_, _| {});
+        assert!(matches!(result, Err(SynthesisError::AssignmentMismatch(_))));
+        let mut cs = expected.clone();
+        let result = assignment.generate_constraints_from(&mut cs, super::SynthesisCursor::new(4, true), |_, _| {});
+        assert!(matches!(result, Err(SynthesisError::AssignmentMismatch(_))));
+        let result = assignment.generate_constraints_from(&mut cs, super::SynthesisCursor::new(6, true), |_, _| {});
+        assert!(matches!(result, Err(SynthesisError::AssignmentMismatch(_))));
+
+        // Ensure resuming from a lookup constraint without the lookup tables is rejected.
+        let mut interrupted = assignment.clone();
+        interrupted.lookup_constraints.truncate(1);
+        let mut cs = super::RecordingConstraintSystem::new();
+        interrupted.generate_constraints(&mut cs).unwrap();
+        let result = assignment.generate_constraints_from(&mut cs, super::SynthesisCursor::new(4, false), |_, _| {});
+        assert!(matches!(result, Err(SynthesisError::AssignmentMismatch(_))));
+    }
+
+    #[test]
+    fn test_generate_constraints_named() {
+        let mut assignment = create_example_assignment::<Fr>();
+
+        // Ensure the default names are used by `generate_constraints`.
+        assignment.private[2] += Fr::one();
+        let mut cs = snarkvm_algorithms::r1cs::TestConstraintSystem::new();
+        assignment.generate_constraints(&mut cs).unwrap();
+        assert_eq!(Some("Constraint 1".to_string()), cs.which_is_unsatisfied());
+
+        // Ensure the given names are used.
+        let symbols = ["x", "x^2", "x^3"];
+        let constraints = ["x^2 = x * x", "x^3 = x^2 * x", "x^3 + x + 5 = out"];
+        let mut cs = snarkvm_algorithms::r1cs::TestConstraintSystem::new();
+        assignment
+            .generate_constraints_named(
+                &mut cs,
+                |i| format!("out {i}"),
+                |i| symbols[i as usize].to_string(),
+                |i| constraints[i as usize].to_string(),
+            )
+            .unwrap();
+        assert_eq!(Some("x^3 = x^2 * x".to_string()), cs.which_is_unsatisfied());
+        assert_eq!(Fr::from(27u64) + Fr::one(), cs.get("x^3"));
+        assert_eq!(Fr::from(35u64), cs.get("out 1"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_same_circuit_base() {
+        let base = create_example_assignment::<Fr>().to_same_circuit_base();
+        let mut witness = (*base).clone();
+        witness.private[0] = Fr::from(4u64);
+
+        let candidate = super::SameCircuitAssignment::create_with_base(base.clone(), witness).unwrap();
+        assert!(std::sync::Arc::ptr_eq(&base, candidate.base()));
+        assert_eq!(base.constraints(), candidate.constraints());
+
+        // Ensure the sizes are those of the base.
+        assert_eq!(3, candidate.num_constraints());
+        assert_eq!(0, candidate.num_lookup_constraints());
+        assert_eq!((5, 3, 3), candidate.num_nonzeros());
+    }
+
+    #[test]
+    fn test_evaluate_lc() {
+        use snarkvm_fields::One;
+
+        let assignment = create_example_assignment::<Fr>();
+
+        // Ensure each side of each constraint evaluates as expected.
+        let (a, b, c) = &assignment.constraints[2];
+        assert_eq!(Some(Fr::from(35u64)), assignment.evaluate_lc(a));
+        assert_eq!(Some(Fr::one()), assignment.evaluate_lc(b));
+        assert_eq!(Some(Fr::from(35u64)), assignment.evaluate_lc(c));
+
+        // Ensure a missing variable evaluates to `None`.
+        let mut lc = a.clone();
+        lc.terms.insert(super::AssignmentVariable::Private(3), Fr::one());
+        assert_eq!(None, assignment.evaluate_lc(&lc));
+    }
+
+    #[test]
+    fn test_constraint_converter_rejects_mismatched_assignments() {
+        use snarkvm_algorithms::r1cs::{SynthesisError, TestConstraintSystem};
+
+        let assignment = create_example_assignment::<Fr>();
+
+        // Ensure non-contiguous private variables are rejected.
+        let mut other = assignment.clone();
+        let value = other.private.swap_remove(&1).unwrap();
+        other.private.insert(3, value);
+        let result = other.generate_constraints(&mut TestConstraintSystem::new());
+        assert!(matches!(result, Err(SynthesisError::AssignmentMismatch(_))));
+
+        // Ensure a constraint referencing a missing variable is rejected.
+        let mut other = assignment.clone();
+        other.private.pop();
+        let result = other.generate_constraints(&mut TestConstraintSystem::new());
+        assert!(matches!(result, Err(SynthesisError::AssignmentMismatch(_))));
+
+        // Ensure a constraint system that is not clean is rejected.
+        let mut cs = TestConstraintSystem::new();
+        assignment.generate_constraints(&mut cs).unwrap();
+        let result = assignment.generate_constraints(&mut cs);
+        assert!(matches!(result, Err(SynthesisError::AssignmentMismatch(_))));
+    }
+
+    #[test]
+    fn test_to_usize_index() {
+        use snarkvm_algorithms::r1cs::SynthesisError;
+
+        assert_eq!(0, super::to_usize_index(0, 0).unwrap());
+        assert_eq!(8, super::to_usize_index(7, 1).unwrap());
+
+        // Ensure an index that overflows with its offset is rejected.
+        assert!(matches!(super::to_usize_index(u64::MAX, 1), Err(SynthesisError::Overflow(_))));
+
+        // Ensure an index that does not fit in a `usize` is rejected.
+        let result = super::to_usize_index(u64::MAX, 0);
+        match usize::BITS < u64::BITS {
+            true => assert!(matches!(result, Err(SynthesisError::Overflow(_)))),
+            false => assert_eq!(usize::MAX, result.unwrap()),
+        }
+        let result = super::to_usize_index(u32::MAX as u64, 1);
+        match usize::BITS < u64::BITS {
+            true => assert!(matches!(result, Err(SynthesisError::Overflow(_)))),
+            false => assert_eq!(1 << 32, result.unwrap()),
+        }
+    }
+
+    #[test]
+    fn test_constraint_converter_over_other_fields() {
+        fn check<F: snarkvm_fields::Field>() {
+            use super::AssignmentVariable::*;
+
+            let mut assignment = create_example_assignment::<F>();
+
+            let mut cs = snarkvm_algorithms::r1cs::TestConstraintSystem::<F>::new();
+            assignment.generate_constraints(&mut cs).unwrap();
+            {
+                use snarkvm_algorithms::r1cs::ConstraintSystem;
+                assert_eq!(assignment.num_public() + 1, cs.num_public_variables() as u64);
+                assert_eq!(assignment.num_private(), cs.num_private_variables() as u64);
+                assert_eq!(assignment.num_constraints(), cs.num_constraints() as u64);
+                assert!(cs.is_satisfied());
+            }
+
+            // Add `(-x + 1 + 0*x^2) * (0*x^3 + 0) == 0`, which exercises the negative and zero coefficients,
+            // and the zero constants.
+            let (zero, one) = (F::zero(), F::one());
+            let (mut a, mut b, mut c) = assignment.constraints[0].clone();
+            a.constant = one;
+            a.terms = [(Private(0), -one), (Private(1), zero)].into_iter().collect();
+            b.constant = zero;
+            b.terms = [(Private(2), zero)].into_iter().collect();
+            c.terms.clear();
+            assignment.append_constraint(a, b, c);
+            assert!(assignment.is_satisfied());
+            assert_systems_equivalent(&assignment, &assignment.clone().canonical_form());
+
+            // Ensure an unsatisfied witness is detected by both the assignment and the constraint system.
+            assignment.private[0] += one;
+            let mut cs = snarkvm_algorithms::r1cs::TestConstraintSystem::<F>::new();
+            assignment.generate_constraints(&mut cs).unwrap();
+            assert!(!assignment.is_satisfied());
+            assert_eq!(Some("Constraint 0".to_string()), cs.which_is_unsatisfied());
+        }
+
+        // The scalar field of BLS12-377.
+        check::<Fr>();
+        // The base field of BLS12-377, used when recursing over BLS12-377 proofs.
+        check::<snarkvm_curves::bls12_377::Fq>();
+        // A quadratic extension of the BLS12-377 base field.
+        check::<snarkvm_curves::bls12_377::Fq2>();
+        // The scalar field of the Edwards curve over the BLS12-377 scalar field, which is a smaller field.
+        check::<snarkvm_curves::edwards_bls12::Fr>();
+    }
+
+    #[test]
+    fn test_varuna_with_shared_assignment() {
+        use snarkvm_algorithms::{
+            crypto_hash::PoseidonSponge,
+            snark::varuna::{ahp::AHPForR1CS, VarunaHidingMode, VarunaSNARK},
+        };
+        use snarkvm_curves::bls12_377::{Bls12_377, Fq};
+        use snarkvm_utilities::rand::TestRng;
+
+        type FS = PoseidonSponge<Fq, 2, 1>;
+        type VarunaInst = VarunaSNARK<Bls12_377, FS, VarunaHidingMode>;
+
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(200, 200, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_pp = FS::sample_parameters();
+
+        // Ensure a shared assignment is synthesized without being cloned, both behind an `Arc` and a reference.
+        let assignment = create_example_assignment::<Fr>().to_same_circuit_base();
+        let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &assignment).unwrap();
+        let proof = VarunaInst::prove(universal_prover, &fs_pp, &index_pk, &&*assignment, rng).unwrap();
+        assert!(VarunaInst::verify(universal_verifier, &fs_pp, &index_vk, assignment.public_input_values(), &proof)
+            .unwrap());
+
+        // Ensure the circuit is the same as that of the assignment itself.
+        let (_, expected_vk) = VarunaInst::circuit_setup(&universal_srs, &*assignment).unwrap();
+        assert_eq!(expected_vk, index_vk);
+    }
+
+    #[test]
+    fn test_public_input_slice() {
+        use snarkvm_algorithms::{
+            crypto_hash::PoseidonSponge,
+            snark::varuna::{ahp::AHPForR1CS, VarunaHidingMode, VarunaSNARK},
+        };
+        use snarkvm_curves::bls12_377::{Bls12_377, Fq};
+        use snarkvm_utilities::rand::TestRng;
+
+        type FS = PoseidonSponge<Fq, 2, 1>;
+        type VarunaInst = VarunaSNARK<Bls12_377, FS, VarunaHidingMode>;
+
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(200, 200, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_pp = FS::sample_parameters();
+
+        let assignment = create_example_assignment::<Fr>();
+        let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &assignment).unwrap();
+        let proof = VarunaInst::prove(universal_prover, &fs_pp, &index_pk, &assignment, rng).unwrap();
+        let verify = |inputs: &[Fr]| VarunaInst::verify(universal_verifier, &fs_pp, &index_vk, inputs, &proof).unwrap();
+
+        // Ensure the public input slice is accepted by the verifier.
+        let inputs = assignment.public_input_slice();
+        assert_eq!(vec![Fr::one(), Fr::from(35u64)], inputs);
+        assert_eq!(assignment.public_input_values(), inputs);
+        assert!(verify(&inputs));
+
+        // Ensure a perturbed, reordered, or shifted slice is rejected.
+        for i in 0..inputs.len() {
+            let mut perturbed = inputs.clone();
+            perturbed[i] += Fr::one();
+            assert!(!verify(&perturbed));
+        }
+        assert!(!verify(&inputs.iter().rev().copied().collect::<Vec<_>>()));
+        assert!(!verify(&[Fr::one(), Fr::one(), Fr::from(35u64)]));
+        assert!(!verify(&inputs[1..]));
+    }
+
+    #[test]
+    fn test_varuna() {
+        let _candidate_output = create_example_circuit::<Circuit>();
+        let assignment = Circuit::eject_assignment_and_reset();
+        assert_eq!(0, Circuit::num_constants());
+        assert_eq!(1, Circuit::num_public());
+        assert_eq!(0, Circuit::num_private());
+        assert_eq!(0, Circuit::num_constraints());
+
+        // Varuna setup, prove, and verify.
+
+        use snarkvm_algorithms::{
+            crypto_hash::PoseidonSponge,
+            snark::varuna::{ahp::AHPForR1CS, VarunaHidingMode, VarunaSNARK},
+        };
+        use snarkvm_curves::bls12_377::{Bls12_377, Fq};
+        use snarkvm_utilities::rand::TestRng;
+
+        type FS = PoseidonSponge<Fq, 2, 1>;
+        type VarunaInst = VarunaSNARK<Bls12_377, FS, VarunaHidingMode>;
+
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(200, 200, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_pp = FS::sample_parameters();
+
+        let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &assignment).unwrap();
+        println!("Called circuit setup");
+
+        let proof = VarunaInst::prove(universal_prover, &fs_pp, &index_pk, &assignment, rng).unwrap();
+        println!("Called prover");
+
+        let one = <Circuit as Environment>::BaseField::one();
+        assert!(VarunaInst::verify(universal_verifier, &fs_pp, &index_vk, [one, one], &proof).unwrap());
+        println!("Called verifier");
+        println!("\nShould not verify (i.e. verifier messages should print below):");
+        assert!(!VarunaInst::verify(universal_verifier, &fs_pp, &index_vk, [one, one + one], &proof).unwrap());
+    }
+}
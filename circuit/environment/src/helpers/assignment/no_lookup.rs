@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An assignment that is synthesized without its lookup tables and lookup constraints,
+/// for backends that do not support lookups. The public and private variables are allocated as usual.
+#[derive(Copy, Clone, Debug)]
+pub struct NoLookupAssignment<'a, F: Field> {
+    assignment: &'a Assignment<F>,
+    strict: bool,
+}
+
+impl<'a, F: Field> NoLookupAssignment<'a, F> {
+    /// Initializes a new assignment that omits the lookups of the given assignment.
+    /// If `strict` is set, synthesis fails if the given assignment contains any lookup constraints,
+    /// instead of silently dropping them.
+    pub const fn new(assignment: &'a Assignment<F>, strict: bool) -> Self {
+        Self { assignment, strict }
+    }
+}
+
+impl<F: Field> snarkvm_algorithms::r1cs::ConstraintSynthesizer<F> for NoLookupAssignment<'_, F> {
+    /// Synthesizes the constraints from the environment into a `snarkvm_algorithms::r1cs`-compliant constraint system,
+    /// omitting the lookup tables and lookup constraints.
+    fn generate_constraints<CS: snarkvm_algorithms::r1cs::ConstraintSystem<F>>(
+        &self,
+        cs: &mut CS,
+    ) -> Result<(), snarkvm_algorithms::r1cs::SynthesisError> {
+        // Ensure no lookup constraints are dropped, if strict.
+        if self.strict && !self.assignment.lookup_constraints.is_empty() {
+            return Err(snarkvm_algorithms::r1cs::SynthesisError::AssignmentMismatch(format!(
+                "The assignment contains {} lookup constraints, which cannot be synthesized without lookups",
+                self.assignment.lookup_constraints.len()
+            )));
+        }
+        self.assignment.synthesize(
+            cs,
+            SynthesisVariables::of(self.assignment),
+            false,
+            &SynthesisNames::default(),
+            Default::default(),
+            |_, _| {},
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError, TestConstraintSystem};
+    use snarkvm_curves::bls12_377::Fr;
+
+    #[test]
+    fn test_no_lookup_assignment() {
+        let assignment = super::super::tests::create_example_assignment_with_lookup::<Fr>();
+
+        // Ensure the lookups are omitted, and the variables are allocated as usual.
+        let mut without_lookups = assignment.clone();
+        without_lookups.tables.clear();
+        without_lookups.lookup_constraints.clear();
+        let mut expected = TestConstraintSystem::new();
+        without_lookups.generate_constraints(&mut expected).unwrap();
+        let mut candidate = TestConstraintSystem::new();
+        NoLookupAssignment::new(&assignment, false).generate_constraints(&mut candidate).unwrap();
+        assert!(candidate.is_satisfied());
+        assert_eq!(expected.num_public_variables(), candidate.num_public_variables());
+        assert_eq!(expected.num_private_variables(), candidate.num_private_variables());
+        assert_eq!(expected.num_constraints(), candidate.num_constraints());
+        assert_eq!(assignment.num_constraints() as usize, candidate.num_constraints());
+
+        // Ensure strict synthesis rejects the lookup constraints.
+        let mut candidate = TestConstraintSystem::new();
+        let result = NoLookupAssignment::new(&assignment, true).generate_constraints(&mut candidate);
+        assert!(matches!(result, Err(SynthesisError::AssignmentMismatch(_))));
+
+        // Ensure strict synthesis accepts an assignment without lookup constraints.
+        let mut candidate = TestConstraintSystem::new();
+        NoLookupAssignment::new(&without_lookups, true).generate_constraints(&mut candidate).unwrap();
+        assert!(candidate.is_satisfied());
+    }
+}
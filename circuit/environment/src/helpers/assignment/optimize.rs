@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The constant and the terms of a linear combination, with the terms sorted by variable.
+/// Two linear combinations are equal iff their keys are equal.
+type LCKey<'a, F> = (F, Vec<(&'a AssignmentVariable<F>, &'a F)>);
+
+impl<F: Field> AssignmentLC<F> {
+    /// Returns the key of the linear combination, which is independent of the order of its terms.
+    fn to_key(&self) -> LCKey<'_, F> {
+        let mut terms = self.terms.iter().collect::<Vec<_>>();
+        terms.sort_unstable();
+        (self.constant, terms)
+    }
+}
+
+impl<F: Field> Assignment<F> {
+    /// Removes every constraint that is equal to an earlier constraint, and every lookup constraint that is equal
+    /// to an earlier lookup constraint on the same table, and returns the total number removed.
+    /// Linear combinations are compared irrespective of the order of their terms, and the order of the remaining
+    /// constraints is preserved. As only exact duplicates are removed, the satisfiability of the assignment is preserved.
+    pub fn remove_redundant_constraints(&mut self) -> usize {
+        let num_constraints = self.constraints.len() + self.lookup_constraints.len();
+
+        // Determine the first occurrence of each constraint.
+        let keep = {
+            let mut seen = IndexSet::with_capacity(self.constraints.len());
+            self.constraints
+                .iter()
+                .map(|(a, b, c)| seen.insert([a.to_key(), b.to_key(), c.to_key()]))
+                .collect::<Vec<_>>()
+        };
+        let mut keep = keep.into_iter();
+        self.constraints.retain(|_| keep.next().unwrap_or(true));
+
+        // Determine the first occurrence of each lookup constraint.
+        let keep = {
+            let mut seen = IndexSet::with_capacity(self.lookup_constraints.len());
+            self.lookup_constraints
+                .iter()
+                .map(|(a, b, c, table_index)| seen.insert(([a.to_key(), b.to_key(), c.to_key()], *table_index)))
+                .collect::<Vec<_>>()
+        };
+        let mut keep = keep.into_iter();
+        self.lookup_constraints.retain(|_| keep.next().unwrap_or(true));
+
+        num_constraints - (self.constraints.len() + self.lookup_constraints.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::r1cs::{ConstraintSynthesizer, TestConstraintSystem};
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::One;
+
+    /// Returns `true` if the assignment synthesizes into a satisfied constraint system.
+    fn is_cs_satisfied(assignment: &Assignment<Fr>) -> bool {
+        let mut cs = TestConstraintSystem::new();
+        assignment.generate_constraints(&mut cs).unwrap();
+        cs.is_satisfied()
+    }
+
+    #[test]
+    fn test_remove_redundant_constraints() {
+        let mut assignment = super::super::tests::create_example_assignment::<Fr>();
+        let expected = assignment.clone();
+        assert_eq!(0, assignment.remove_redundant_constraints());
+        assert_eq!(expected, assignment);
+
+        // Duplicate the first constraint, and the last constraint with its terms reordered.
+        let first = assignment.constraints[0].clone();
+        let mut last = assignment.constraints[2].clone();
+        last.0.terms.reverse();
+        assignment.constraints.insert(1, first);
+        assignment.constraints.push(last);
+        assert_eq!(5, assignment.num_constraints());
+
+        // Ensure the duplicates are removed, and the satisfiability is preserved.
+        assert!(is_cs_satisfied(&assignment));
+        assert_eq!(2, assignment.remove_redundant_constraints());
+        assert_eq!(expected, assignment);
+        assert!(is_cs_satisfied(&assignment));
+
+        // Ensure an unsatisfied assignment remains unsatisfied.
+        let mut other = assignment.clone();
+        other.private[1] += Fr::one();
+        other.constraints.push(other.constraints[0].clone());
+        assert!(!is_cs_satisfied(&other));
+        assert_eq!(1, other.remove_redundant_constraints());
+        assert!(!is_cs_satisfied(&other));
+
+        // Ensure only the lookup constraints on the same table are removed.
+        let variable = |index| super::super::tests::lc(AssignmentVariable::Private(index));
+        let table = super::super::tests::create_example_table::<Fr>();
+        assignment.tables = vec![table.clone(), table];
+        for table_index in [0, 1, 0] {
+            assignment.lookup_constraints.push((variable(0), variable(1), variable(2), table_index));
+        }
+        assert!(assignment.is_satisfied());
+        assert_eq!(1, assignment.remove_redundant_constraints());
+        assert_eq!(3, assignment.num_constraints());
+        assert_eq!(
+            vec![0, 1],
+            assignment.lookup_constraints().iter().map(|(_, _, _, index)| *index).collect::<Vec<_>>()
+        );
+        assert!(assignment.is_satisfied());
+    }
+}
@@ -0,0 +1,252 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_algorithms::r1cs::{ConstraintSystem, LinearCombination, SynthesisError, Variable};
+
+/// A constraint system that records the variables, constraints, lookup tables, and lookup constraints
+/// it is given into an assignment, which is the reverse of [`Assignment::generate_constraints`].
+///
+/// The constant `1` of the constraint system is folded into the constants of the linear combinations,
+/// and public variable `i + 1` of the constraint system is recorded as public variable `i` of the assignment.
+/// As such, synthesizing an assignment into this constraint system records an equal assignment.
+/// A gadget synthesized directly should allocate the constant `1` as its first public variable,
+/// which the assignment expects as its own first public variable.
+///
+/// The annotations and namespaces are ignored.
+#[derive(Clone, Debug)]
+pub struct RecordingConstraintSystem<F: Field> {
+    assignment: Assignment<F>,
+}
+
+impl<F: Field> Default for RecordingConstraintSystem<F> {
+    /// Initializes an empty recording constraint system.
+    fn default() -> Self {
+        let assignment = Assignment {
+            public: Default::default(),
+            private: Default::default(),
+            constraints: Default::default(),
+            tables: Default::default(),
+            lookup_constraints: Default::default(),
+            circuit_version: None,
+        };
+        Self { assignment }
+    }
+}
+
+impl<F: Field> RecordingConstraintSystem<F> {
+    /// Initializes an empty recording constraint system.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the assignment recorded so far.
+    pub const fn assignment(&self) -> &Assignment<F> {
+        &self.assignment
+    }
+
+    /// Returns the recorded assignment.
+    pub fn into_assignment(self) -> Assignment<F> {
+        self.assignment
+    }
+
+    /// Returns the given linear combination of the constraint system as a linear combination of the assignment.
+    /// The coefficients of repeated variables are summed, and zero coefficients are kept as they are given.
+    fn record_lc(lc: LinearCombination<F>) -> AssignmentLC<F> {
+        let mut recorded = AssignmentLC { constant: F::zero(), terms: IndexMap::with_capacity(lc.0.len()) };
+        for (variable, coefficient) in lc.0 {
+            let variable = match variable.get_unchecked() {
+                snarkvm_algorithms::r1cs::Index::Public(0) => {
+                    recorded.constant += coefficient;
+                    continue;
+                }
+                snarkvm_algorithms::r1cs::Index::Public(index) => AssignmentVariable::Public(index as Index - 1),
+                snarkvm_algorithms::r1cs::Index::Private(index) => AssignmentVariable::Private(index as Index),
+            };
+            *recorded.terms.entry(variable).or_insert_with(F::zero) += coefficient;
+        }
+        recorded
+    }
+}
+
+impl<F: Field> ConstraintSystem<F> for RecordingConstraintSystem<F> {
+    type Root = Self;
+
+    fn add_lookup_table(&mut self, table: LookupTable<F>) {
+        self.assignment.tables.push(table);
+    }
+
+    fn alloc<FN, A, AR>(&mut self, _annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let index = self.assignment.allocate_private(f()?);
+        Ok(Variable::new_unchecked(snarkvm_algorithms::r1cs::Index::Private(index as usize)))
+    }
+
+    fn alloc_input<FN, A, AR>(&mut self, _annotation: A, f: FN) -> Result<Variable, SynthesisError>
+    where
+        FN: FnOnce() -> Result<F, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+    {
+        let index = self.assignment.allocate_public(f()?);
+        Ok(Variable::new_unchecked(snarkvm_algorithms::r1cs::Index::Public(index as usize + 1)))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        let a = Self::record_lc(a(LinearCombination::zero()));
+        let b = Self::record_lc(b(LinearCombination::zero()));
+        let c = Self::record_lc(c(LinearCombination::zero()));
+        self.assignment.append_constraint(a, b, c);
+    }
+
+    fn enforce_lookup<A, AR, LA, LB, LC>(
+        &mut self,
+        _annotation: A,
+        a: LA,
+        b: LB,
+        c: LC,
+        table_index: usize,
+    ) -> Result<(), SynthesisError>
+    where
+        A: FnOnce() -> AR,
+        AR: AsRef<str>,
+        LA: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LB: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+        LC: FnOnce(LinearCombination<F>) -> LinearCombination<F>,
+    {
+        // Ensure the lookup table exists.
+        if table_index >= self.assignment.tables.len() {
+            return Err(SynthesisError::LookupTableMissing);
+        }
+
+        let a = Self::record_lc(a(LinearCombination::zero()));
+        let b = Self::record_lc(b(LinearCombination::zero()));
+        let c = Self::record_lc(c(LinearCombination::zero()));
+        self.assignment.lookup_constraints.push((a, b, c, table_index));
+        Ok(())
+    }
+
+    fn push_namespace<NR, N>(&mut self, _name_fn: N)
+    where
+        NR: AsRef<str>,
+        N: FnOnce() -> NR,
+    {
+    }
+
+    fn pop_namespace(&mut self) {}
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+
+    fn num_constraints(&self) -> usize {
+        self.assignment.constraints.len() + self.assignment.lookup_constraints.len()
+    }
+
+    fn num_public_variables(&self) -> usize {
+        self.assignment.public.len() + 1
+    }
+
+    fn num_private_variables(&self) -> usize {
+        self.assignment.private.len()
+    }
+
+    fn is_in_setup_mode(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::r1cs::ConstraintSynthesizer;
+    use snarkvm_circuit::prelude::{Circuit, Environment};
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+
+    /// Returns the assignment recorded by synthesizing the given synthesizer.
+    fn record<C: ConstraintSynthesizer<Fr>>(synthesizer: &C) -> Assignment<Fr> {
+        let mut cs = RecordingConstraintSystem::new();
+        synthesizer.generate_constraints(&mut cs).unwrap();
+        cs.into_assignment()
+    }
+
+    #[test]
+    fn test_recording_constraint_system() {
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+        assert_eq!(assignment, record(&assignment));
+
+        // Ensure the zero coefficients and the lookups are recorded.
+        let mut assignment = super::super::tests::create_example_assignment_with_lookup::<Fr>();
+        assignment.lookup_constraints[0].0.terms.insert(AssignmentVariable::Public(1), Fr::zero());
+        let recorded = record(&assignment);
+        assert_eq!(assignment, recorded);
+        assert!(recorded.validate_lookups().is_ok());
+
+        // Ensure a lookup on a missing table is rejected.
+        let mut cs = RecordingConstraintSystem::<Fr>::new();
+        let result = cs.enforce_lookup(|| "lookup", |lc| lc, |lc| lc, |lc| lc, 0);
+        assert!(matches!(result, Err(SynthesisError::LookupTableMissing)));
+    }
+
+    #[test]
+    fn test_recording_ejected_assignment() {
+        let _candidate_output = super::super::tests::create_example_circuit::<Circuit>();
+        let ejected = Circuit::eject_assignment_and_reset();
+
+        // Ensure the recorded assignment matches the ejected assignment.
+        let recorded = record(&ejected);
+        assert_eq!(ejected.to_dimacs_like_debug(), recorded.to_dimacs_like_debug());
+        assert_eq!(ejected.split_public(), recorded.split_public());
+        assert!(recorded.validate().is_ok());
+        assert!(recorded.is_satisfied());
+    }
+
+    #[test]
+    fn test_recording_gadget() {
+        /// Enforces `x * x == y`, where `y` is public, and `x` is private.
+        struct Square(Fr);
+
+        impl ConstraintSynthesizer<Fr> for Square {
+            fn generate_constraints<CS: ConstraintSystem<Fr>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+                let _one = cs.alloc_input(|| "one", || Ok(Fr::one()))?;
+                let y = cs.alloc_input(|| "y", || Ok(self.0 * self.0))?;
+                let x = cs.alloc(|| "x", || Ok(self.0))?;
+                cs.enforce(|| "x * x == y", |lc| lc + x, |lc| lc + x, |lc| lc + y + (Fr::zero(), CS::one()));
+                Ok(())
+            }
+        }
+
+        let recorded = record(&Square(Fr::from(3u64)));
+        assert_eq!(vec![Fr::one(), Fr::from(9u64)], recorded.public_input_values());
+        assert_eq!(1, recorded.num_constraints());
+        assert!(recorded.validate().is_ok());
+        assert!(recorded.is_satisfied());
+
+        // Ensure the recorded assignment synthesizes back into the same assignment.
+        assert_eq!(recorded, record(&recorded));
+    }
+}
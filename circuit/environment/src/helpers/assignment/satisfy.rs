@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The index of a constraint that is not satisfied by an assignment.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UnsatisfiedConstraint {
+    /// The index of an unsatisfied constraint.
+    Constraint(usize),
+    /// The index of an unsatisfied lookup constraint.
+    LookupConstraint(usize),
+}
+
+impl<F: Field> Assignment<F> {
+    /// Returns `true` if the assignment satisfies all of its constraints and lookup constraints.
+    ///
+    /// This evaluates each constraint directly under the assignment, which is far cheaper than
+    /// synthesizing the assignment into a constraint system. Returns `false` if a constraint references
+    /// a variable that is not in the assignment, or a lookup constraint references a missing table or row.
+    pub fn is_satisfied(&self) -> bool {
+        self.find_unsatisfied_constraint().is_none()
+    }
+
+    /// Returns the first constraint that is not satisfied by the assignment, followed by the first lookup constraint,
+    /// or `None` if the assignment satisfies all of its constraints and lookup constraints.
+    /// See [`Assignment::is_satisfied`] for the conditions under which a constraint is unsatisfied.
+    pub fn find_unsatisfied_constraint(&self) -> Option<UnsatisfiedConstraint> {
+        // Find the first constraint `A * B == C` that does not hold.
+        let constraint = self.constraints.iter().position(|(a, b, c)| {
+            match (self.evaluate_lc(a), self.evaluate_lc(b), self.evaluate_lc(c)) {
+                (Some(a), Some(b), Some(c)) => a * b != c,
+                _ => true,
+            }
+        });
+        if let Some(index) = constraint {
+            return Some(UnsatisfiedConstraint::Constraint(index));
+        }
+
+        // Find the first lookup constraint `(A, B) -> C` that is not a row of its table.
+        self.lookup_constraints
+            .iter()
+            .position(|(a, b, c, table_index)| {
+                match (self.tables.get(*table_index), self.evaluate_lc(a), self.evaluate_lc(b), self.evaluate_lc(c)) {
+                    (Some(table), Some(a), Some(b), Some(c)) => {
+                        !matches!(table.lookup(&[a, b]), Some((_, _, value)) if *value == c)
+                    }
+                    _ => true,
+                }
+            })
+            .map(UnsatisfiedConstraint::LookupConstraint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_circuit::prelude::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::One;
+
+    #[test]
+    fn test_is_satisfied() {
+        let _candidate_output = super::super::tests::create_example_circuit::<Circuit>();
+        let assignment = Circuit::eject_assignment_and_reset();
+        assert!(assignment.is_satisfied());
+
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+        assert!(assignment.is_satisfied());
+
+        // Ensure an incorrect witness is not satisfied.
+        let mut other = assignment.clone();
+        other.private[1] += Fr::one();
+        assert!(!other.is_satisfied());
+
+        // Ensure a missing variable is not satisfied.
+        let mut other = assignment.clone();
+        other.private.pop();
+        assert!(!other.is_satisfied());
+        assert_eq!(Some(UnsatisfiedConstraint::Constraint(1)), other.find_unsatisfied_constraint());
+
+        // Ensure a lookup of a row of the table is satisfied.
+        let mut assignment = super::super::tests::create_example_assignment_with_lookup::<Fr>();
+        assert!(assignment.is_satisfied());
+        let variable = |index| super::super::tests::lc(AssignmentVariable::Private(index));
+
+        // Ensure a lookup with a missing row is not satisfied.
+        let mut other = assignment.clone();
+        other.lookup_constraints.push((variable(1), variable(0), variable(2), 0));
+        assert!(!other.is_satisfied());
+        assert_eq!(Some(UnsatisfiedConstraint::LookupConstraint(1)), other.find_unsatisfied_constraint());
+
+        // Ensure a lookup with a mismatched output is not satisfied.
+        let mut other = assignment.clone();
+        other.lookup_constraints.push((variable(0), variable(1), variable(1), 0));
+        assert!(!other.is_satisfied());
+
+        // Ensure a lookup on a missing table is not satisfied.
+        assignment.lookup_constraints.push((variable(0), variable(1), variable(2), 1));
+        assert!(!assignment.is_satisfied());
+    }
+
+    #[test]
+    fn test_find_unsatisfied_constraint() {
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+        assert_eq!(None, assignment.find_unsatisfied_constraint());
+
+        // Ensure the first unsatisfied constraint is found.
+        let mut other = assignment.clone();
+        other.private[2] += Fr::one();
+        assert_eq!(Some(UnsatisfiedConstraint::Constraint(1)), other.find_unsatisfied_constraint());
+        other.private[1] += Fr::one();
+        assert_eq!(Some(UnsatisfiedConstraint::Constraint(0)), other.find_unsatisfied_constraint());
+    }
+}
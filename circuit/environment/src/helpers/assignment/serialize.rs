@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use serde::{de, Deserialize, Deserializer, Serializer};
+use alloc::collections::BTreeMap;
+
+/// Serializes the terms of a linear combination as a sequence of `(variable, coefficient)` pairs.
+pub(super) fn serialize_terms<F: Field, S: Serializer>(
+    terms: &IndexMap<AssignmentVariable<F>, F>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(terms)
+}
+
+/// Deserializes the terms of a linear combination from a sequence of `(variable, coefficient)` pairs.
+pub(super) fn deserialize_terms<'de, F: Field, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<IndexMap<AssignmentVariable<F>, F>, D::Error> {
+    let pairs = Vec::<(AssignmentVariable<F>, F)>::deserialize(deserializer)?;
+    let num_pairs = pairs.len();
+    let terms = pairs.into_iter().collect::<IndexMap<_, _>>();
+    match terms.len() == num_pairs {
+        true => Ok(terms),
+        false => Err(de::Error::custom("Found a linear combination with a duplicate term")),
+    }
+}
+
+/// Serializes the variables as a map from index to value.
+pub(super) fn serialize_variables<F: Field, S: Serializer>(
+    variables: &IndexMap<Index, F>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(variables)
+}
+
+/// Deserializes the variables in index order, ensuring their indices are contiguous, starting from zero.
+pub(super) fn deserialize_variables<'de, F: Field, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<IndexMap<Index, F>, D::Error> {
+    let variables = BTreeMap::<Index, F>::deserialize(deserializer)?;
+    match variables.keys().copied().eq(0..variables.len() as u64) {
+        true => Ok(variables.into_iter().collect()),
+        false => Err(de::Error::custom("Found variable indices that are not contiguous from zero")),
+    }
+}
+
+/// Serializes the lookup tables, with each table as a sequence of `(key, value)` entries.
+pub(super) fn serialize_tables<F: Field, S: Serializer>(
+    tables: &[LookupTable<F>],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(tables.iter().map(|table| table.table.iter().collect::<Vec<_>>()))
+}
+
+/// Deserializes the lookup tables, with each table as a sequence of `(key, value)` entries.
+pub(super) fn deserialize_tables<'de, F: Field, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<LookupTable<F>>, D::Error> {
+    Vec::<Vec<([F; 2], F)>>::deserialize(deserializer)?
+        .into_iter()
+        .map(|entries| {
+            let mut table = LookupTable::default();
+            for (key, value) in entries {
+                if table.fill(key, value).is_some() {
+                    return Err(de::Error::custom("Found a lookup table with a duplicate entry"));
+                }
+            }
+            Ok(table)
+        })
+        .collect()
+}
+
+impl<F: Field> Assignment<F> {
+    /// Returns a compact JSON summary of the structure of the assignment, without any of its field elements.
+    /// The summary has the number of public variables, private variables, constraints, lookup tables,
+    /// and lookup constraints, the number of nonzeros in `A`, `B`, and `C`, and the number of rows in each lookup table.
+    pub fn to_json_summary(&self) -> String {
+        serde_json::json!({
+            "num_public": self.num_public(),
+            "num_private": self.num_private(),
+            "num_constraints": self.num_constraints(),
+            "num_lookup_tables": self.num_lookup_tables(),
+            "num_lookup_constraints": self.num_lookup_constraints(),
+            "num_nonzeros": self.num_nonzeros(),
+            "table_rows": self.tables.iter().map(|table| table.table.len()).collect::<Vec<_>>(),
+        })
+        .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let expected = super::super::tests::create_example_assignment_with_lookup::<Fr>();
+
+        // Serialize
+        let candidate_string = serde_json::to_string(&expected)?;
+        let value = serde_json::from_str::<serde_json::Value>(&candidate_string)?;
+        assert_eq!(value["public"]["1"], "35");
+        assert_eq!(value["constraints"][0][0]["terms"][0][0], serde_json::json!({ "type": "private", "value": 0 }));
+
+        // Deserialize
+        let candidate = serde_json::from_str::<Assignment<Fr>>(&candidate_string)?;
+        assert_eq!(expected, candidate);
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_json_summary() -> Result<()> {
+        let mut assignment = super::super::tests::create_example_assignment::<Fr>();
+        let mut table = super::super::tests::create_example_table::<Fr>();
+        table.fill([Fr::from(2u64), Fr::from(4u64)], Fr::from(8u64));
+        assignment.tables.push(table);
+
+        let summary = serde_json::from_str::<serde_json::Value>(&assignment.to_json_summary())?;
+        let expected = serde_json::json!({
+            "num_public": 2,
+            "num_private": 3,
+            "num_constraints": 3,
+            "num_lookup_tables": 1,
+            "num_lookup_constraints": 0,
+            "num_nonzeros": [5, 3, 3],
+            "table_rows": [2],
+        });
+        assert_eq!(expected, summary);
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_json_rejects_non_contiguous_variables() -> Result<()> {
+        let mut assignment = super::super::tests::create_example_assignment::<Fr>();
+        let value = assignment.private.swap_remove(&1).unwrap();
+        assignment.private.insert(3, value);
+
+        let string = serde_json::to_string(&assignment)?;
+        assert!(serde_json::from_str::<Assignment<Fr>>(&string).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,231 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_algorithms::r1cs::SynthesisError;
+
+use core::ops::Deref;
+
+/// An assignment that is known to satisfy the invariants checked by [`Assignment::validate`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidatedAssignment<F: Field>(Assignment<F>);
+
+impl<F: Field> TryFrom<Assignment<F>> for ValidatedAssignment<F> {
+    type Error = SynthesisError;
+
+    /// Validates the given assignment, returning an error if it does not satisfy the invariants.
+    fn try_from(assignment: Assignment<F>) -> Result<Self, Self::Error> {
+        assignment.validate()?;
+        Ok(Self(assignment))
+    }
+}
+
+impl<F: Field> Deref for ValidatedAssignment<F> {
+    type Target = Assignment<F>;
+
+    /// Returns the validated assignment.
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<F: Field> ValidatedAssignment<F> {
+    /// Returns the validated assignment.
+    pub fn into_inner(self) -> Assignment<F> {
+        self.0
+    }
+}
+
+impl<F: Field> Assignment<F> {
+    /// Ensures the assignment satisfies the invariants that are otherwise only checked during synthesis:
+    /// 1. The public variables are stored in index order, with indices contiguous from zero.
+    /// 2. The private variables are stored in index order, with indices contiguous from zero.
+    /// 3. The terms of every constraint and lookup constraint do not contain constant variables,
+    ///    and only reference public and private variables that are in the assignment.
+    /// 4. Every lookup constraint references a lookup table of the assignment.
+    ///
+    /// This does not check whether the assignment satisfies its constraints (see [`Assignment::is_satisfied`]).
+    pub fn validate(&self) -> Result<(), SynthesisError> {
+        // Ensure the variables are stored in index order, with indices contiguous from zero.
+        for (mode, variables) in [("Public", &self.public), ("Private", &self.private)] {
+            if let Some((i, index)) = variables.keys().enumerate().find(|(i, index)| *i as u64 != **index) {
+                return Err(SynthesisError::AssignmentMismatch(format!(
+                    "{mode} variable {i} has index {index}, but the indices must be contiguous from 0 and in order"
+                )));
+            }
+        }
+
+        // Ensure the terms of a linear combination reference the variables of the assignment.
+        let validate_lc = |constraint: &str, lc: &AssignmentLC<F>| {
+            for variable in lc.terms.keys() {
+                match variable {
+                    AssignmentVariable::Constant(_) => {
+                        return Err(SynthesisError::AssignmentMismatch(format!(
+                            "{constraint} has a constant variable in its terms"
+                        )));
+                    }
+                    AssignmentVariable::Public(index) if *index >= self.num_public() => {
+                        return Err(SynthesisError::AssignmentMismatch(format!(
+                            "{constraint} references public variable {index}, but there are only {} public variables",
+                            self.num_public()
+                        )));
+                    }
+                    AssignmentVariable::Private(index) if *index >= self.num_private() => {
+                        return Err(SynthesisError::AssignmentMismatch(format!(
+                            "{constraint} references private variable {index}, but there are only {} private variables",
+                            self.num_private()
+                        )));
+                    }
+                    _ => continue,
+                }
+            }
+            Ok(())
+        };
+        for (i, (a, b, c)) in self.constraints.iter().enumerate() {
+            let constraint = format!("Constraint {i}");
+            [a, b, c].into_iter().try_for_each(|lc| validate_lc(&constraint, lc))?;
+        }
+        for (i, (a, b, c, _)) in self.lookup_constraints.iter().enumerate() {
+            let constraint = format!("Lookup constraint {i}");
+            [a, b, c].into_iter().try_for_each(|lc| validate_lc(&constraint, lc))?;
+        }
+
+        // Ensure the lookup constraints reference existing tables.
+        self.validate_lookup_indices()
+    }
+
+    /// Panics with the violated invariant if the assignment does not satisfy the invariants checked by
+    /// [`Assignment::validate`], which include the terms of every linear combination being free of constant
+    /// variables. This is meant to be called right after modifying an assignment by hand, to surface a corruption
+    /// where it is introduced, rather than as a mismatch during synthesis.
+    ///
+    /// As with `debug_assert!`, the check is only performed in builds with `debug_assertions` enabled.
+    #[track_caller]
+    pub fn assert_well_formed(&self) {
+        #[cfg(debug_assertions)]
+        if let Err(error) = self.validate() {
+            panic!("The assignment is not well-formed: {error}");
+        }
+    }
+
+    /// Returns the largest public index and the largest private index that are referenced by the terms
+    /// of the constraints and lookup constraints, or `None` if no such variable is referenced.
+    ///
+    /// In a well-formed assignment, these are less than [`Assignment::num_public`] and [`Assignment::num_private`].
+    /// A larger index references a variable that was never allocated (see [`Assignment::validate`]).
+    pub fn max_variable_index(&self) -> (Option<Index>, Option<Index>) {
+        let (mut max_public, mut max_private) = (None, None);
+        for variable in self.linear_combinations().flat_map(|lc| lc.terms.keys()) {
+            match variable {
+                AssignmentVariable::Constant(_) => continue,
+                AssignmentVariable::Public(index) => max_public = max_public.max(Some(*index)),
+                AssignmentVariable::Private(index) => max_private = max_private.max(Some(*index)),
+            }
+        }
+        (max_public, max_private)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::One;
+
+    #[test]
+    fn test_max_variable_index() {
+        let mut assignment = super::super::tests::create_example_assignment::<Fr>();
+        assert_eq!((Some(1), Some(2)), assignment.max_variable_index());
+
+        // Ensure the lookup constraints are included.
+        let (a, b, mut c) = assignment.constraints[0].clone();
+        c.terms.insert(AssignmentVariable::Private(7), Fr::one());
+        assignment.lookup_constraints.push((a, b, c, 0));
+        assert_eq!((Some(1), Some(7)), assignment.max_variable_index());
+        assert!(assignment.validate().is_err());
+
+        // Ensure an assignment without references has no maximum.
+        assert_eq!((None, None), Assignment::<Fr>::new().max_variable_index());
+    }
+
+    #[test]
+    fn test_assert_well_formed() {
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+        assignment.assert_well_formed();
+
+        // Ensure a constant variable in the terms panics, in builds with debug assertions.
+        let mut other = assignment;
+        other.constraints[1].2.terms.insert(AssignmentVariable::Constant(Fr::one()), Fr::one());
+        let result = std::panic::catch_unwind(|| other.assert_well_formed());
+        assert_eq!(cfg!(debug_assertions), result.is_err());
+    }
+
+    #[test]
+    fn test_validate() {
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+        assert!(assignment.validate().is_ok());
+        let validated = ValidatedAssignment::try_from(assignment.clone()).unwrap();
+        assert_eq!(assignment, *validated);
+        assert_eq!(assignment, validated.into_inner());
+
+        let error = |assignment: Assignment<Fr>| ValidatedAssignment::try_from(assignment).unwrap_err().to_string();
+
+        // Ensure non-contiguous indices are rejected.
+        let mut other = assignment.clone();
+        let value = other.public.swap_remove(&1).unwrap();
+        other.public.insert(2, value);
+        assert_eq!(
+            "Assignment mismatch: Public variable 1 has index 2, but the indices must be contiguous from 0 and in order",
+            error(other)
+        );
+
+        // Ensure out-of-order indices are rejected.
+        let mut other = assignment.clone();
+        other.private.reverse();
+        assert_eq!(
+            "Assignment mismatch: Private variable 0 has index 2, but the indices must be contiguous from 0 and in order",
+            error(other)
+        );
+
+        // Ensure constant variables in the terms are rejected.
+        let mut other = assignment.clone();
+        other.constraints[1].2.terms.insert(AssignmentVariable::Constant(Fr::one()), Fr::one());
+        assert_eq!("Assignment mismatch: Constraint 1 has a constant variable in its terms", error(other));
+
+        // Ensure out-of-range variables are rejected.
+        let mut other = assignment.clone();
+        other.constraints[2].0.terms.insert(AssignmentVariable::Public(2), Fr::one());
+        assert_eq!(
+            "Assignment mismatch: Constraint 2 references public variable 2, but there are only 2 public variables",
+            error(other)
+        );
+        let mut other = assignment.clone();
+        let (a, b, _) = other.constraints[0].clone();
+        let mut c = a.clone();
+        c.terms.insert(AssignmentVariable::Private(3), Fr::one());
+        other.lookup_constraints.push((a, b, c, 0));
+        assert_eq!(
+            "Assignment mismatch: Lookup constraint 0 references private variable 3, but there are only 3 private variables",
+            error(other.clone())
+        );
+
+        // Ensure out-of-range tables are rejected.
+        other.lookup_constraints[0].2.terms.swap_remove(&AssignmentVariable::Private(3));
+        assert_eq!(
+            "Assignment mismatch: Lookup constraint 0 references table 0, but there are only 0 tables",
+            error(other)
+        );
+    }
+}
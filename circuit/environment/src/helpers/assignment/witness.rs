@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<F: Field> Assignment<F> {
+    /// Returns a map from the name of each public and private variable to its value,
+    /// where each name is given by the `labeler`. The public variables precede the private variables.
+    /// Note: The `labeler` must return a unique name for each variable, otherwise values are overwritten.
+    pub fn to_named_witness_map(&self, labeler: impl Fn(Mode, Index) -> String) -> IndexMap<String, F> {
+        self.public
+            .iter()
+            .map(|(index, value)| (labeler(Mode::Public, *index), *value))
+            .chain(self.private.iter().map(|(index, value)| (labeler(Mode::Private, *index), *value)))
+            .collect()
+    }
+
+    /// Returns a copy of the assignment with every public and private value set to zero.
+    /// The variable indices, constraints, lookup tables, and lookup constraints are kept, so the copy
+    /// may be passed to the circuit setup in place of the original, without revealing the witness.
+    pub fn strip_witness(&self) -> Self {
+        let mut assignment = self.clone();
+        assignment.public.values_mut().for_each(|value| *value = F::zero());
+        assignment.private.values_mut().for_each(|value| *value = F::zero());
+        assignment
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::Zero;
+
+    #[test]
+    fn test_to_named_witness_map() {
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+
+        let witness = assignment.to_named_witness_map(|mode, index| format!("{mode:?}_{index}"));
+        assert_eq!((assignment.num_public() + assignment.num_private()) as usize, witness.len());
+
+        for (index, value) in assignment.public_inputs() {
+            assert_eq!(Some(value), witness.get(&format!("Public_{index}")));
+        }
+        for (index, value) in assignment.private_inputs() {
+            assert_eq!(Some(value), witness.get(&format!("Private_{index}")));
+        }
+    }
+
+    #[test]
+    fn test_strip_witness() {
+        use snarkvm_algorithms::{
+            crypto_hash::PoseidonSponge,
+            snark::varuna::{ahp::AHPForR1CS, VarunaHidingMode, VarunaSNARK},
+            SNARK,
+        };
+        use snarkvm_curves::bls12_377::{Bls12_377, Fq};
+
+        type VarunaInst = VarunaSNARK<Bls12_377, PoseidonSponge<Fq, 2, 1>, VarunaHidingMode>;
+
+        let assignment = super::super::tests::create_example_assignment::<Fr>();
+        let stripped = assignment.strip_witness();
+        assert!(stripped.public.values().chain(stripped.private.values()).all(|value| value.is_zero()));
+        assert!(assignment.is_key_compatible(&stripped));
+
+        // Ensure the stripped assignment produces the same keys.
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(200, 200, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let (_, expected) = VarunaInst::circuit_setup(&universal_srs, &assignment).unwrap();
+        let (_, candidate) = VarunaInst::circuit_setup(&universal_srs, &stripped).unwrap();
+        assert_eq!(expected, candidate);
+    }
+}
@@ -43,3 +43,6 @@ pub use variable::*;
 
 pub mod r1cs;
 pub use r1cs::*;
+
+#[cfg(feature = "std")]
+mod r1cs_export;
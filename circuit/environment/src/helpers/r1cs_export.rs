@@ -0,0 +1,313 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{Assignment, AssignmentLC};
+
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{error, ToBytes};
+
+use std::io::{self, Write};
+
+/// The magic bytes of a `.r1cs` file.
+const R1CS_MAGIC: &[u8; 4] = b"r1cs";
+/// The version of the `.r1cs` format.
+const R1CS_VERSION: u32 = 1;
+/// The magic bytes of a `.wtns` file.
+const WTNS_MAGIC: &[u8; 4] = b"wtns";
+/// The version of the `.wtns` format.
+const WTNS_VERSION: u32 = 2;
+/// The section type of the header, in both formats.
+const HEADER_SECTION: u32 = 1;
+/// The section type of the constraints, in the `.r1cs` format.
+const CONSTRAINTS_SECTION: u32 = 2;
+/// The section type of the wire-to-label map, in the `.r1cs` format.
+const WIRE_TO_LABEL_SECTION: u32 = 3;
+/// The section type of the witness, in the `.wtns` format.
+const WITNESS_SECTION: u32 = 2;
+
+/// Converts the given value to a `u32`, as used for sizes and wires in both formats.
+fn to_u32(value: usize) -> io::Result<u32> {
+    u32::try_from(value).map_err(|_| error("Exceeded the limits of the circom file format"))
+}
+
+/// Returns the size of a field element in bytes, followed by the modulus of the field, as used in both formats.
+fn field_header<F: PrimeField>() -> io::Result<Vec<u8>> {
+    let mut modulus = Vec::new();
+    F::modulus().write_le(&mut modulus)?;
+    let mut header = Vec::new();
+    to_u32(modulus.len())?.write_le(&mut header)?;
+    header.extend_from_slice(&modulus);
+    Ok(header)
+}
+
+/// Writes a file with the given magic bytes, version, and `(section type, section)` pairs.
+fn write_sections<W: Write>(
+    mut writer: W,
+    magic: &[u8; 4],
+    version: u32,
+    sections: &[(u32, Vec<u8>)],
+) -> io::Result<()> {
+    writer.write_all(magic)?;
+    version.write_le(&mut writer)?;
+    to_u32(sections.len())?.write_le(&mut writer)?;
+    for (section_type, section) in sections {
+        section_type.write_le(&mut writer)?;
+        (section.len() as u64).write_le(&mut writer)?;
+        writer.write_all(section)?;
+    }
+    Ok(())
+}
+
+impl<F: PrimeField> Assignment<F> {
+    /// Writes the constraint system of the assignment in the circom `.r1cs` binary format.
+    ///
+    /// Wire `0` is the constant wire, followed by one wire per public variable, and then one wire
+    /// per private variable, matching the constraint system the assignment is synthesized into.
+    /// All public variables are written as public inputs, and all private variables as private inputs.
+    /// Lookup constraints have no counterpart in the format, and are rejected, as are constraints
+    /// that reference a variable beyond the allocated variables, as they would exceed the number of wires.
+    pub fn write_r1cs<W: Write>(&self, writer: W) -> io::Result<()> {
+        // Ensure the assignment does not contain lookup constraints.
+        if !self.lookup_constraints.is_empty() {
+            return Err(error("Lookup constraints cannot be exported to the '.r1cs' format"));
+        }
+        // Ensure the constraints only reference allocated variables, so every wire is within the header.
+        let (max_public, max_private) = self.max_variable_index();
+        if matches!(max_public, Some(index) if index >= self.num_public())
+            || matches!(max_private, Some(index) if index >= self.num_private())
+        {
+            return Err(error("The constraints reference a variable that has not been allocated"));
+        }
+
+        let num_wires = to_u32(1 + self.public.len() + self.private.len())?;
+
+        // Construct the header.
+        let mut header = field_header::<F>()?;
+        num_wires.write_le(&mut header)?;
+        0u32.write_le(&mut header)?;
+        to_u32(self.public.len())?.write_le(&mut header)?;
+        to_u32(self.private.len())?.write_le(&mut header)?;
+        u64::from(num_wires).write_le(&mut header)?;
+        to_u32(self.constraints.len())?.write_le(&mut header)?;
+
+        // Construct the constraints.
+        let mut constraints = Vec::new();
+        let mut write_lc = |lc: &AssignmentLC<F>| -> io::Result<()> {
+            let entries = self.wire_entries(lc);
+            to_u32(entries.len())?.write_le(&mut constraints)?;
+            for (wire, coefficient) in entries {
+                to_u32(wire)?.write_le(&mut constraints)?;
+                coefficient.write_le(&mut constraints)?;
+            }
+            Ok(())
+        };
+        for (a, b, c) in &self.constraints {
+            write_lc(a)?;
+            write_lc(b)?;
+            write_lc(c)?;
+        }
+
+        // Construct the wire-to-label map, where each wire is its own label.
+        let mut labels = Vec::new();
+        for wire in 0..u64::from(num_wires) {
+            wire.write_le(&mut labels)?;
+        }
+
+        // Write the file.
+        write_sections(writer, R1CS_MAGIC, R1CS_VERSION, &[
+            (HEADER_SECTION, header),
+            (CONSTRAINTS_SECTION, constraints),
+            (WIRE_TO_LABEL_SECTION, labels),
+        ])
+    }
+
+    /// Writes the witness of the assignment in the circom `.wtns` binary format.
+    ///
+    /// The witness is the constant `1`, followed by the public variables in index order, and then
+    /// the private variables in index order, matching the wires written by [`Assignment::write_r1cs`].
+    pub fn write_witness<W: Write>(&self, writer: W) -> io::Result<()> {
+        // Order the variables by index.
+        let mut public = self.public.iter().collect::<Vec<_>>();
+        public.sort_unstable_by_key(|(index, _)| **index);
+        let mut private = self.private.iter().collect::<Vec<_>>();
+        private.sort_unstable_by_key(|(index, _)| **index);
+
+        // Construct the header.
+        let mut header = field_header::<F>()?;
+        to_u32(1 + public.len() + private.len())?.write_le(&mut header)?;
+
+        // Construct the witness.
+        let mut witness = Vec::new();
+        F::one().write_le(&mut witness)?;
+        for (_, value) in public.into_iter().chain(private) {
+            value.write_le(&mut witness)?;
+        }
+
+        // Write the file.
+        write_sections(writer, WTNS_MAGIC, WTNS_VERSION, &[(HEADER_SECTION, header), (WITNESS_SECTION, witness)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::assignment::Matrix;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::FromBytes;
+
+    /// The header of a `.r1cs` file.
+    struct Header {
+        num_wires: u32,
+        num_public_outputs: u32,
+        num_public_inputs: u32,
+        num_private_inputs: u32,
+        num_constraints: u32,
+    }
+
+    /// Reads the header and the `(wire, coefficient)` entries of the `A`, `B`, and `C` rows of every constraint.
+    #[allow(clippy::type_complexity)]
+    fn read_r1cs(mut reader: &[u8]) -> io::Result<(Header, Vec<[Vec<(u32, Fr)>; 3]>)> {
+        let mut magic = [0u8; 4];
+        io::Read::read_exact(&mut reader, &mut magic)?;
+        assert_eq!(R1CS_MAGIC, &magic);
+        assert_eq!(R1CS_VERSION, u32::read_le(&mut reader)?);
+
+        let (mut header, mut constraints) = (None, Vec::new());
+        for _ in 0..u32::read_le(&mut reader)? {
+            let section_type = u32::read_le(&mut reader)?;
+            let section_size = u64::read_le(&mut reader)? as usize;
+            let (mut section, rest) = reader.split_at(section_size);
+            reader = rest;
+            match section_type {
+                HEADER_SECTION => {
+                    assert_eq!(32, u32::read_le(&mut section)?);
+                    let mut modulus = [0u8; 32];
+                    io::Read::read_exact(&mut section, &mut modulus)?;
+                    assert_eq!(Fr::modulus().to_bytes_le().unwrap(), modulus);
+                    header = Some(Header {
+                        num_wires: u32::read_le(&mut section)?,
+                        num_public_outputs: u32::read_le(&mut section)?,
+                        num_public_inputs: u32::read_le(&mut section)?,
+                        num_private_inputs: u32::read_le(&mut section)?,
+                        num_constraints: {
+                            let _num_labels = u64::read_le(&mut section)?;
+                            u32::read_le(&mut section)?
+                        },
+                    });
+                }
+                CONSTRAINTS_SECTION => {
+                    while !section.is_empty() {
+                        let mut read_lc = || -> io::Result<Vec<(u32, Fr)>> {
+                            (0..u32::read_le(&mut section)?)
+                                .map(|_| Ok((u32::read_le(&mut section)?, Fr::read_le(&mut section)?)))
+                                .collect()
+                        };
+                        constraints.push([read_lc()?, read_lc()?, read_lc()?]);
+                    }
+                }
+                _ => continue,
+            }
+        }
+        Ok((header.unwrap(), constraints))
+    }
+
+    #[test]
+    fn test_write_witness() -> io::Result<()> {
+        let assignment = crate::helpers::assignment::tests::create_example_assignment::<Fr>();
+
+        let mut bytes = Vec::new();
+        assignment.write_witness(&mut bytes)?;
+
+        // Read the header.
+        let mut reader = &bytes[..];
+        let mut magic = [0u8; 4];
+        io::Read::read_exact(&mut reader, &mut magic)?;
+        assert_eq!(WTNS_MAGIC, &magic);
+        assert_eq!(WTNS_VERSION, u32::read_le(&mut reader)?);
+        assert_eq!(2, u32::read_le(&mut reader)?);
+        assert_eq!(HEADER_SECTION, u32::read_le(&mut reader)?);
+        assert_eq!(4 + 32 + 4, u64::read_le(&mut reader)?);
+        assert_eq!(32, u32::read_le(&mut reader)?);
+        let mut modulus = [0u8; 32];
+        io::Read::read_exact(&mut reader, &mut modulus)?;
+        let num_witnesses = u32::read_le(&mut reader)?;
+        assert_eq!(1 + assignment.num_public() + assignment.num_private(), num_witnesses as u64);
+
+        // Read the witness, and ensure it matches the wires of the assignment.
+        assert_eq!(WITNESS_SECTION, u32::read_le(&mut reader)?);
+        assert_eq!(32 * num_witnesses as u64, u64::read_le(&mut reader)?);
+        let witness = (0..num_witnesses).map(|_| Fr::read_le(&mut reader)).collect::<io::Result<Vec<_>>>()?;
+        assert!(reader.is_empty());
+        let expected = [Fr::one()]
+            .into_iter()
+            .chain(assignment.public.values().copied())
+            .chain(assignment.private.values().copied())
+            .collect::<Vec<_>>();
+        assert_eq!(expected, witness);
+
+        // Ensure the witness satisfies the exported constraints.
+        let mut bytes = Vec::new();
+        assignment.write_r1cs(&mut bytes)?;
+        let (_, constraints) = read_r1cs(&bytes)?;
+        let evaluate = |entries: &[(u32, Fr)]| {
+            entries.iter().map(|(wire, coefficient)| witness[*wire as usize] * coefficient).sum::<Fr>()
+        };
+        for [a, b, c] in &constraints {
+            assert_eq!(evaluate(a) * evaluate(b), evaluate(c));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_r1cs() -> io::Result<()> {
+        let mut assignment = crate::helpers::assignment::tests::create_example_assignment::<Fr>();
+
+        let mut bytes = Vec::new();
+        assignment.write_r1cs(&mut bytes)?;
+
+        // Re-import the file, and ensure it matches the assignment.
+        let (header, constraints) = read_r1cs(&bytes)?;
+        assert_eq!(1 + assignment.num_public() + assignment.num_private(), header.num_wires as u64);
+        assert_eq!(0, header.num_public_outputs);
+        assert_eq!(assignment.num_public(), header.num_public_inputs as u64);
+        assert_eq!(assignment.num_private(), header.num_private_inputs as u64);
+        assert_eq!(assignment.num_constraints(), header.num_constraints as u64);
+        assert_eq!(assignment.num_constraints(), constraints.len() as u64);
+
+        // Ensure the entries match the dense matrices of the assignment.
+        for (i, matrix) in [Matrix::A, Matrix::B, Matrix::C].into_iter().enumerate() {
+            let dense = assignment.to_dense(matrix).unwrap();
+            for (row, constraint) in dense.iter().zip(&constraints) {
+                let mut expected = vec![Fr::zero(); row.len()];
+                for (wire, coefficient) in &constraint[i] {
+                    expected[*wire as usize] += coefficient;
+                }
+                assert_eq!(row, &expected);
+            }
+        }
+
+        // Ensure a reference to an unallocated variable is rejected.
+        let mut other = assignment.clone();
+        other.private.pop();
+        assert!(other.write_r1cs(&mut Vec::new()).is_err());
+
+        // Ensure lookup constraints are rejected.
+        let lc = assignment.constraints[0].0.clone();
+        assignment.tables.push(Default::default());
+        assignment.lookup_constraints.push((lc.clone(), lc.clone(), lc, 0));
+        assert!(assignment.write_r1cs(&mut Vec::new()).is_err());
+        Ok(())
+    }
+}
@@ -15,6 +15,7 @@
 #![forbid(unsafe_code)]
 #![allow(clippy::type_complexity)]
 
+extern crate alloc;
 extern crate snarkvm_circuit_environment_witness;
 
 pub use snarkvm_circuit_environment_witness::rename_selfs;